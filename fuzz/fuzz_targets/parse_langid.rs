@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use unic_langid_impl::LanguageIdentifier;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(langid) = LanguageIdentifier::from_bytes(data) {
+        let serialized = langid.to_string();
+        let reparsed: LanguageIdentifier = serialized.parse().unwrap();
+        assert_eq!(langid, reparsed);
+    }
+});
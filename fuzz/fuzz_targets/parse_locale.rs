@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use unic_locale_impl::Locale;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(loc) = Locale::from_bytes(data) {
+        let serialized = loc.to_string();
+        let reparsed: Locale = serialized.parse().unwrap();
+        assert_eq!(loc, reparsed);
+    }
+});
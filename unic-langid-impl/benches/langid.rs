@@ -2,11 +2,17 @@ use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
 use criterion::Criterion;
-use criterion::Fun;
 
 use unic_langid_impl::subtags;
 use unic_langid_impl::LanguageIdentifier;
 
+type PartsTuple = (
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+    Vec<subtags::Variant>,
+);
+
 static STRINGS: &[&str] = &[
     "en-US",
     "en-GB",
@@ -37,48 +43,93 @@ fn language_identifier_construct_bench(c: &mut Criterion) {
         .map(|s| -> LanguageIdentifier { s.parse().unwrap() })
         .collect();
 
-    let funcs = vec![
-        Fun::new("from_str", |b, _| {
-            b.iter(|| {
-                for s in STRINGS {
-                    let _: Result<LanguageIdentifier, _> = black_box(s).parse();
-                }
-            })
-        }),
-        Fun::new("from_bytes", |b, _| {
-            let slices: Vec<&[u8]> = STRINGS.iter().map(|s| s.as_bytes()).collect();
-            b.iter(|| {
-                for s in &slices {
-                    let _ = LanguageIdentifier::from_bytes(black_box(s));
-                }
-            })
-        }),
-        Fun::new("from_parts", |b, langids: &Vec<LanguageIdentifier>| {
-            let entries: Vec<(
-                subtags::Language,
-                Option<subtags::Script>,
-                Option<subtags::Region>,
-                Vec<subtags::Variant>,
-            )> = langids
-                .iter()
-                .cloned()
-                .map(|langid| langid.into_parts())
-                .collect();
-            b.iter(|| {
-                for (language, script, region, variants) in &entries {
-                    let _ = LanguageIdentifier::from_parts(
-                        language.clone(),
-                        script.clone(),
-                        region.clone(),
-                        variants,
-                    );
-                }
-            })
-        }),
-    ];
+    let mut group = c.benchmark_group("language_identifier_construct");
+
+    group.bench_function("from_str", |b| {
+        b.iter(|| {
+            for s in STRINGS {
+                let _: Result<LanguageIdentifier, _> = black_box(s).parse();
+            }
+        })
+    });
+    group.bench_function("from_bytes", |b| {
+        let slices: Vec<&[u8]> = STRINGS.iter().map(|s| s.as_bytes()).collect();
+        b.iter(|| {
+            for s in &slices {
+                let _ = LanguageIdentifier::from_bytes(black_box(s));
+            }
+        })
+    });
+    group.bench_function("from_parts", |b| {
+        let entries: Vec<PartsTuple> = langids
+            .iter()
+            .cloned()
+            .map(|langid| langid.into_parts())
+            .collect();
+        b.iter(|| {
+            for (language, script, region, variants) in &entries {
+                let _ = LanguageIdentifier::from_parts(*language, *script, *region, variants);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+/// The previous `Display` implementation, writing subtag by subtag.
+struct PerSubtag<'l>(&'l LanguageIdentifier);
+
+impl std::fmt::Display for PerSubtag<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        self.0.language.fmt(f)?;
+        if let Some(ref script) = self.0.script {
+            f.write_char('-')?;
+            script.fmt(f)?;
+        }
+        if let Some(ref region) = self.0.region {
+            f.write_char('-')?;
+            region.fmt(f)?;
+        }
+        for variant in self.0.variants() {
+            f.write_char('-')?;
+            variant.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn language_identifier_serialize_bench(c: &mut Criterion) {
+    let langids: Vec<LanguageIdentifier> = STRINGS
+        .iter()
+        .chain(&["ca-Latn-ES-valencia", "de-DE-1996-macos"])
+        .map(|s| -> LanguageIdentifier { s.parse().unwrap() })
+        .collect();
+
+    let mut group = c.benchmark_group("language_identifier_serialize");
+
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            for langid in &langids {
+                let _ = black_box(langid).to_string();
+            }
+        })
+    });
+    group.bench_function("to_string_per_subtag", |b| {
+        b.iter(|| {
+            for langid in &langids {
+                let _ = PerSubtag(black_box(langid)).to_string();
+            }
+        })
+    });
 
-    c.bench_functions("language_identifier_construct", funcs, langids);
+    group.finish();
 }
 
-criterion_group!(benches, language_identifier_construct_bench,);
+criterion_group!(
+    benches,
+    language_identifier_construct_bench,
+    language_identifier_serialize_bench,
+);
 criterion_main!(benches);
@@ -0,0 +1,74 @@
+//! Parsing of HTTP `Accept-Language` headers, as defined in [`RFC 7231`].
+//!
+//! [`RFC 7231`]: https://tools.ietf.org/html/rfc7231#section-5.3.5
+use crate::LanguageIdentifier;
+use alloc::vec::Vec;
+
+/// Parses an HTTP `Accept-Language` header into a list of language
+/// identifiers with their quality values, sorted by decreasing quality.
+///
+/// Entries with the same quality keep the order of the header, and entries
+/// without a `q` parameter have a quality of `1.0`. Malformed entries,
+/// including ones with a malformed quality, are skipped. Entries with a
+/// quality of `0`, which marks them as not acceptable, are skipped as well.
+///
+/// The wildcard `*` is returned as `und`, standing for any language which
+/// doesn't match the other entries.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::parse_accept_language;
+///
+/// let langids = parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5, de;q=0");
+///
+/// assert_eq!(langids.len(), 4);
+/// assert_eq!(langids[0].0, "fr-CH");
+/// assert_eq!(langids[0].1, 1.0);
+/// assert_eq!(langids[2].0, "en");
+/// assert_eq!(langids[2].1, 0.8);
+/// assert_eq!(langids[3].0, "und");
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<(LanguageIdentifier, f32)> {
+    let mut result: Vec<_> = header.split(',').filter_map(parse_entry).collect();
+    // A stable sort keeps the order of entries with the same quality.
+    result.sort_by(|a, b| b.1.total_cmp(&a.1));
+    result
+}
+
+fn parse_entry(entry: &str) -> Option<(LanguageIdentifier, f32)> {
+    let mut parts = entry.split(';').map(str::trim);
+
+    let langid = match parts.next()? {
+        "*" => LanguageIdentifier::default(),
+        range => LanguageIdentifier::from_bytes(range.as_bytes()).ok()?,
+    };
+
+    let mut quality = 1.0;
+    for param in parts {
+        let (name, value) = param.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("q") {
+            quality = parse_quality(value.trim())?;
+        }
+    }
+
+    if quality > 0.0 {
+        Some((langid, quality))
+    } else {
+        None
+    }
+}
+
+/// Parses a quality value, which is a number between `0` and `1` with at
+/// most three decimal digits.
+fn parse_quality(value: &str) -> Option<f32> {
+    let (int, frac) = value.split_once('.').unwrap_or((value, ""));
+    if !matches!(int, "0" | "1")
+        || frac.len() > 3
+        || !frac.bytes().all(|b| b.is_ascii_digit())
+        || (int == "1" && frac.bytes().any(|b| b != b'0'))
+    {
+        return None;
+    }
+    value.parse().ok()
+}
@@ -0,0 +1,111 @@
+//! Lookups into the CLDR `languageAlias`, `scriptAlias` and `territoryAlias`
+//! supplemental data, used to replace deprecated subtags with their modern
+//! equivalents. The tables are generated by the `generate_aliases` binary.
+//!
+//! Territories that were split into several successors are mapped onto the
+//! successor in which the language is most likely spoken, if the
+//! `likelysubtags` feature is enabled, or the first replacement listed by
+//! CLDR otherwise.
+//!
+//! It also contains the BCP 47 grandfathered tags which have a preferred
+//! value in the IANA Language Subtag Registry.
+use crate::aliases_table::{LANGUAGE_ALIASES, SCRIPT_ALIASES, TERRITORY_ALIASES};
+use crate::subtags;
+use crate::LanguageIdentifier;
+
+/// Grandfathered tags with their preferred values. Tags without one, such as
+/// `i-default`, `i-enochian`, `i-mingo`, `zh-min` and `cel-gaulish`, are not
+/// listed.
+static GRANDFATHERED: &[(&str, &str)] = &[
+    ("art-lojban", "jbo"),
+    ("en-gb-oed", "en-GB-oxendict"),
+    ("i-ami", "ami"),
+    ("i-bnn", "bnn"),
+    ("i-hak", "hak"),
+    ("i-klingon", "tlh"),
+    ("i-lux", "lb"),
+    ("i-navajo", "nv"),
+    ("i-pwn", "pwn"),
+    ("i-tao", "tao"),
+    ("i-tay", "tay"),
+    ("i-tsu", "tsu"),
+    ("no-bok", "nb"),
+    ("no-nyn", "nn"),
+    ("sgn-be-fr", "sfb"),
+    ("sgn-be-nl", "vgt"),
+    ("sgn-ch-de", "sgg"),
+    ("zh-guoyu", "cmn"),
+    ("zh-hakka", "hak"),
+    ("zh-min-nan", "nan"),
+    ("zh-xiang", "hsn"),
+];
+
+pub fn language(language: subtags::Language) -> Option<LanguageIdentifier> {
+    let key = language.as_u64()?;
+    let idx = LANGUAGE_ALIASES
+        .binary_search_by_key(&key, |(alias, _)| *alias)
+        .ok()?;
+    let (language, script, region) = LANGUAGE_ALIASES[idx].1;
+    Some(LanguageIdentifier::from_parts(
+        unsafe { subtags::Language::from_raw_unchecked(language) },
+        script.map(|s| unsafe { subtags::Script::from_raw_unchecked(s) }),
+        region.map(|r| unsafe { subtags::Region::from_raw_unchecked(r) }),
+        &[],
+    ))
+}
+
+pub fn script(script: subtags::Script) -> Option<subtags::Script> {
+    let idx = SCRIPT_ALIASES
+        .binary_search_by_key(&script.as_u32(), |(alias, _)| *alias)
+        .ok()?;
+    Some(unsafe { subtags::Script::from_raw_unchecked(SCRIPT_ALIASES[idx].1) })
+}
+
+pub fn region(
+    region: subtags::Region,
+    language: subtags::Language,
+    script: Option<subtags::Script>,
+) -> Option<subtags::Region> {
+    let idx = TERRITORY_ALIASES
+        .binary_search_by_key(&region.as_u32(), |(alias, _)| *alias)
+        .ok()?;
+    let mut replacements = TERRITORY_ALIASES[idx]
+        .1
+        .iter()
+        .map(|r| unsafe { subtags::Region::from_raw_unchecked(*r) });
+    let first = replacements.next();
+
+    #[cfg(feature = "likelysubtags")]
+    {
+        if let Some((_, _, Some(likely))) = crate::likelysubtags::maximize(language, script, None) {
+            if first == Some(likely) || replacements.any(|r| r == likely) {
+                return Some(likely);
+            }
+        }
+    }
+    #[cfg(not(feature = "likelysubtags"))]
+    let _ = (language, script, replacements);
+
+    first
+}
+
+pub fn grandfathered(tag: &[u8]) -> Option<&'static str> {
+    GRANDFATHERED
+        .iter()
+        .find(|(gf, _)| {
+            gf.len() == tag.len()
+                && gf.bytes().zip(tag).all(|(a, b)| match b {
+                    b'_' => a == b'-',
+                    b => a == b.to_ascii_lowercase(),
+                })
+        })
+        .map(|(_, preferred)| *preferred)
+}
+
+#[test]
+fn tables_are_sorted() {
+    assert!(LANGUAGE_ALIASES.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(SCRIPT_ALIASES.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(TERRITORY_ALIASES.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(GRANDFATHERED.windows(2).all(|w| w[0].0 < w[1].0));
+}
@@ -0,0 +1,785 @@
+#![allow(clippy::type_complexity)]
+#![allow(clippy::unreadable_literal)]
+
+pub static LANGUAGE_ALIASES: [(u64, (u64, Option<u32>, Option<u32>)); 423] = [
+    (26722, (7301218, None, None)),
+    (26739, (29299, Some(1853120844), None)),
+    (26986, (27001, None, None)),
+    (27764, (7104870, None, None)),
+    (28265, (25705, None, None)),
+    (28525, (28530, None, None)),
+    (30569, (25960, None, None)),
+    (30570, (30314, None, None)),
+    (30580, (27489, None, None)),
+    (6382200, (7889251, None, None)),
+    (6382451, (6646888, None, None)),
+    (6382952, (7236968, None, None)),
+    (6383715, (26723, None, None)),
+    (6383732, (26740, None, None)),
+    (6383738, (24954, None, None)),
+    (6383992, (7234401, None, None)),
+    (6384481, (27489, None, None)),
+    (6384483, (7499107, None, None)),
+    (6384743, (25703, None, None)),
+    (6385017, (7500396, None, None)),
+    (6385257, (24937, None, None)),
+    (6385267, (28275, None, None)),
+    (6385776, (7956066, None, None)),
+    (6385779, (29541, None, None)),
+    (6386273, (29281, None, None)),
+    (6386278, (29286, None, None)),
+    (6386541, (29549, None, None)),
+    (6386793, (29801, None, None)),
+    (6387051, (27243, None, None)),
+    (6387297, (30305, None, None)),
+    (6387571, (30579, None, None)),
+    (6388071, (6382183, None, None)),
+    (6388077, (31085, None, None)),
+    (6388078, (31086, None, None)),
+    (6448488, (25960, None, None)),
+    (6449524, (28514, None, None)),
+    (6450018, (7037541, None, None)),
+    (6450273, (29043, None, None)),
+    (6450800, (6840684, None, None)),
+    (6450803, (6382185, None, None)),
+    (6451054, (25198, None, None)),
+    (6451809, (29281, None, None)),
+    (6452322, (6448482, None, None)),
+    (6452588, (30060, None, None)),
+    (6453626, (24954, None, None)),
+    (6453877, (31349, None, None)),
+    (6513005, (27501, None, None)),
+    (6513255, (7958135, None, None)),
+    (6513506, (7102818, None, None)),
+    (6513523, (29299, None, None)),
+    (6514539, (6710388, None, None)),
+    (6514804, (7303284, None, None)),
+    (6515042, (7498082, None, None)),
+    (6516080, (7828840, None, None)),
+    (6516331, (29291, None, None)),
+    (6517363, (25459, None, None)),
+    (6518118, (26214, None, None)),
+    (6518643, (30579, None, Some(17475))),
+    (6578542, (7958136, None, None)),
+    (6578809, (6908786, None, None)),
+    (6579321, (27001, None, None)),
+    (6580075, (7431022, None, None)),
+    (6580324, (7501677, None, None)),
+    (6580596, (6583401, None, None)),
+    (6580601, (27001, None, None)),
+    (6580834, (7107172, None, None)),
+    (6581101, (27501, None, None)),
+    (6581347, (7502195, None, None)),
+    (6581358, (27758, None, None)),
+    (6581619, (6450539, None, None)),
+    (6581865, (25705, None, None)),
+    (6581875, (25715, None, None)),
+    (6582114, (28514, None, None)),
+    (6582899, (25459, None, None)),
+    (6582901, (29301, None, None)),
+    (6583137, (8023667, None, None)),
+    (6583652, (6845557, None, None)),
+    (6584163, (29283, None, None)),
+    (6584173, (7826788, None, None)),
+    (6584685, (6778721, None, None)),
+    (6644585, (29545, None, None)),
+    (6644846, (25710, None, None)),
+    (6645859, (25955, None, None)),
+    (6646132, (7561586, None, None)),
+    (6646134, (26998, None, None)),
+    (6646633, (30057, None, None)),
+    (6646887, (24935, None, None)),
+    (6646889, (25961, None, None)),
+    (6647155, (25971, None, None)),
+    (6647412, (7037291, None, None)),
+    (6647928, (6647915, None, None)),
+    (6648418, (29282, None, None)),
+    (6648419, (29283, None, None)),
+    (6648422, (29286, None, None)),
+    (6648423, (27749, None, None)),
+    (6649185, (8025195, None, None)),
+    (6649201, (30065, None, None)),
+    (6649441, (25953, None, None)),
+    (6649701, (25957, None, None)),
+    (6649715, (30323, None, None)),
+    (6649963, (6583924, None, None)),
+    (6650216, (31080, None, None)),
+    (6650465, (31329, None, None)),
+    (6650467, (29539, None, None)),
+    (6709604, (6975076, None, None)),
+    (6709870, (7697006, None, None)),
+    (6712948, (24934, None, Some(17985))),
+    (6713197, (7630456, None, None)),
+    (6714228, (6971764, None, None)),
+    (6775155, (26483, None, None)),
+    (6776170, (6453615, None, None)),
+    (6776172, (7106149, None, None)),
+    (6776173, (7498083, None, None)),
+    (6776692, (7367266, None, None)),
+    (6777205, (26485, None, None)),
+    (6777455, (27247, None, None)),
+    (6777954, (6382185, None, None)),
+    (6777959, (27751, None, None)),
+    (6777965, (26477, None, None)),
+    (6778469, (28261, None, None)),
+    (6778475, (26475, None, None)),
+    (6779489, (28257, None, None)),
+    (6780263, (28263, None, None)),
+    (6780268, (26476, None, None)),
+    (6840685, (26733, None, None)),
+    (6840692, (31092, None, None)),
+    (6842219, (7105899, None, None)),
+    (6842722, (7301218, None, None)),
+    (6843256, (7823735, None, None)),
+    (6843745, (28001, None, None)),
+    (6844274, (28018, None, None)),
+    (6845028, (28269, None, None)),
+    (6846323, (30579, None, None)),
+    (6847083, (7104356, None, None)),
+    (6906234, (7364986, None, None)),
+    (6906473, (6385775, None, None)),
+    (6906735, (25455, None, None)),
+    (6908003, (26746, None, None)),
+    (6908265, (26985, None, None)),
+    (6908268, (7430514, None, None)),
+    (6908527, (27247, None, None)),
+    (6909031, (7043691, None, None)),
+    (6909040, (26992, None, None)),
+    (6910062, (25966, None, None)),
+    (6910323, (29043, None, None)),
+    (6910573, (26989, None, None)),
+    (6910575, (29295, None, None)),
+    (6911079, (6519150, None, None)),
+    (6911860, (27489, None, None)),
+    (6912617, (6388325, None, None)),
+    (6973798, (27238, None, None)),
+    (6975339, (7763819, None, None)),
+    (6976376, (6976883, None, None)),
+    (6976868, (7698276, None, None)),
+    (6976871, (30055, None, None)),
+    (6977389, (6971766, None, None)),
+    (6978145, (31329, None, None)),
+    (6978155, (7369828, None, None)),
+    (7037282, (24930, None, None)),
+    (7037292, (7369579, None, None)),
+    (7037537, (25185, None, None)),
+    (7037548, (6516322, None, None)),
+    (7038836, (26484, None, None)),
+    (7039074, (7103078, None, None)),
+    (7039083, (28269, None, None)),
+    (7039332, (7235940, None, None)),
+    (7039339, (26987, None, None)),
+    (7039845, (29797, None, None)),
+    (7039859, (6453615, None, None)),
+    (7039860, (7174004, None, None)),
+    (7040115, (27507, None, None)),
+    (7040355, (6841208, None, None)),
+    (7040357, (7233901, None, None)),
+    (7040621, (7233901, None, None)),
+    (7040885, (6384997, None, None)),
+    (7041129, (27497, None, None)),
+    (7041893, (27497, None, None)),
+    (7042420, (27508, None, None)),
+    (7043170, (7959916, None, None)),
+    (7102827, (27755, None, None)),
+    (7102829, (27757, None, None)),
+    (7103086, (29294, None, None)),
+    (7103330, (7039330, None, None)),
+    (7103842, (25954, None, None)),
+    (7103860, (25972, None, None)),
+    (7103863, (31075, None, None)),
+    (7104371, (7041897, None, None)),
+    (7104372, (7104870, None, None)),
+    (7105124, (6650468, None, None)),
+    (7105380, (6582625, None, None)),
+    (7105637, (27749, None, None)),
+    (7105641, (7171177, None, None)),
+    (7106413, (28530, None, None)),
+    (7106416, (27760, None, None)),
+    (7106422, (28534, None, None)),
+    (7106423, (28535, None, None)),
+    (7107433, (29545, None, None)),
+    (7107448, (7234916, None, None)),
+    (7107938, (26466, None, None)),
+    (7107942, (26214, None, None)),
+    (7107955, (6580083, None, None)),
+    (7107962, (30074, None, None)),
+    (7108452, (7627364, None, None)),
+    (7108715, (7697003, None, None)),
+    (7168353, (7561569, None, None)),
+    (7168354, (28002, None, None)),
+    (7168372, (24948, None, None)),
+    (7169890, (6775650, None, None)),
+    (7170155, (28011, None, None)),
+    (7170408, (7893619, None, None)),
+    (7170412, (26988, None, None)),
+    (7171436, (7892338, None, None)),
+    (7171947, (30315, None, None)),
+    (7171955, (28531, None, None)),
+    (7172705, (31080, None, None)),
+    (7172715, (6712674, None, None)),
+    (7172719, (28015, None, None)),
+    (7172961, (29537, None, None)),
+    (7172986, (29549, None, None)),
+    (7173490, (28530, None, None)),
+    (7173491, (7826549, None, None)),
+    (7174497, (31073, None, None)),
+    (7174499, (31075, None, None)),
+    (7233892, (24932, None, None)),
+    (7233899, (28267, None, None)),
+    (7233904, (24944, None, None)),
+    (7233907, (24947, None, None)),
+    (7234664, (6906216, None, None)),
+    (7234914, (28258, None, None)),
+    (7234934, (25974, None, None)),
+    (7234937, (7433849, None, None)),
+    (7235431, (7501415, None, None)),
+    (7235942, (26982, None, None)),
+    (7235944, (26984, None, None)),
+    (7235947, (30578, None, None)),
+    (7235948, (28268, None, None)),
+    (7235955, (26995, None, None)),
+    (7236718, (6584929, None, None)),
+    (7236727, (24951, None, None)),
+    (7236963, (26746, None, None)),
+    (7237227, (7040875, None, None)),
+    (7237483, (26475, None, None)),
+    (7237485, (28269, None, None)),
+    (7237490, (28530, None, None)),
+    (7237492, (28532, None, None)),
+    (7237738, (24938, None, None)),
+    (7238247, (28263, None, None)),
+    (7238516, (28276, None, None)),
+    (7239016, (30056, None, None)),
+    (7239023, (6971766, None, None)),
+    (7239026, (28274, None, None)),
+    (7239027, (30067, None, None)),
+    (7240309, (31349, None, None)),
+    (7299430, (28518, None, None)),
+    (7299436, (28524, None, None)),
+    (7299437, (26989, None, None)),
+    (7299687, (6451815, None, None)),
+    (7299689, (26473, None, None)),
+    (7300201, (28521, None, None)),
+    (7300206, (26478, None, None)),
+    (7300455, (24939, None, None)),
+    (7300964, (6909796, None, None)),
+    (7300967, (6779749, None, None)),
+    (7301240, (26744, None, None)),
+    (7301242, (26746, None, None)),
+    (7301479, (7696225, None, None)),
+    (7302252, (7628654, None, None)),
+    (7302259, (27507, None, None)),
+    (7302504, (28520, None, None)),
+    (7302515, (28019, None, None)),
+    (7302759, (7237479, None, None)),
+    (7302766, (28270, None, None)),
+    (7303022, (6583396, None, None)),
+    (7303269, (28517, None, None)),
+    (7304052, (29556, None, None)),
+    (7305828, (31332, None, None)),
+    (7364964, (8022638, None, None)),
+    (7364979, (7631201, None, None)),
+    (7365486, (8021099, None, None)),
+    (7365729, (31332, None, None)),
+    (7365998, (25966, None, None)),
+    (7366497, (6713441, None, None)),
+    (7367781, (7433569, None, None)),
+    (7368052, (6977908, None, None)),
+    (7368309, (7303799, None, None)),
+    (7368811, (7170922, None, None)),
+    (7369331, (29299, None, None)),
+    (7370093, (6971762, None, None)),
+    (7430498, (30053, None, None)),
+    (7431011, (6908786, None, None)),
+    (7432548, (6388346, None, None)),
+    (7432802, (6519394, None, None)),
+    (7434872, (7826788, None, None)),
+    (7435380, (6843764, None, None)),
+    (7436139, (7168377, None, None)),
+    (7496033, (24929, None, None)),
+    (7496042, (7038826, None, None)),
+    (7496045, (29293, None, None)),
+    (7496560, (7890017, None, None)),
+    (7496563, (29288, None, None)),
+    (7497063, (25956, None, None)),
+    (7497064, (31336, None, None)),
+    (7497072, (24934, None, None)),
+    (7497313, (26209, None, None)),
+    (7497575, (7697511, None, None)),
+    (7497837, (7170147, None, None)),
+    (7498091, (31083, None, None)),
+    (7498100, (26996, None, None)),
+    (7498106, (7758707, None, None)),
+    (7498339, (7171949, None, None)),
+    (7498613, (27509, None, None)),
+    (7498862, (7041646, None, None)),
+    (7499115, (30059, None, None)),
+    (7499122, (7892325, None, None)),
+    (7499363, (29299, None, Some(17741))),
+    (7499619, (30571, None, None)),
+    (7499627, (28523, None, None)),
+    (7499630, (28526, None, None)),
+    (7499632, (29808, None, None)),
+    (7499641, (28537, None, None)),
+    (7499888, (7431020, None, None)),
+    (7500388, (7043691, None, None)),
+    (7500392, (7102826, None, None)),
+    (7500907, (7369828, None, None)),
+    (7501154, (31085, None, None)),
+    (7501163, (30059, None, None)),
+    (7501172, (29300, None, None)),
+    (7501922, (6387042, None, None)),
+    (7502177, (31073, None, None)),
+    (7561574, (24934, None, None)),
+    (7561579, (29547, None, None)),
+    (7561832, (29299, Some(1853120844), None)),
+    (7562595, (29539, None, None)),
+    (7562608, (24934, None, None)),
+    (7563617, (6909281, None, None)),
+    (7563618, (26978, None, None)),
+    (7564385, (29043, None, None)),
+    (7564910, (7496302, None, None)),
+    (7565154, (29538, None, None)),
+    (7565155, (28515, None, None)),
+    (7565177, (7171962, None, None)),
+    (7565936, (24934, None, Some(17985))),
+    (7566191, (29551, None, None)),
+    (7566446, (6973808, None, None)),
+    (7566693, (30053, None, None)),
+    (7566704, (29552, None, None)),
+    (7566706, (30066, None, None)),
+    (7566955, (6972519, None, None)),
+    (7566956, (30316, None, None)),
+    (7627107, (24931, None, None)),
+    (7627110, (27489, None, None)),
+    (7627112, (29800, None, None)),
+    (7627115, (24939, None, None)),
+    (7627116, (24940, None, None)),
+    (7627120, (7501931, None, None)),
+    (7627124, (29812, None, None)),
+    (7629156, (6711652, None, None)),
+    (7629164, (29804, None, None)),
+    (7629175, (7106414, None, None)),
+    (7629409, (6448481, None, None)),
+    (7629933, (29805, None, None)),
+    (7629936, (26477, None, None)),
+    (7630201, (7173229, None, None)),
+    (7630445, (7237239, None, None)),
+    (7630707, (29811, None, None)),
+    (7631717, (29797, None, None)),
+    (7631725, (7959149, None, None)),
+    (7632228, (27758, None, None)),
+    (7633261, (7959149, None, None)),
+    (7633515, (7369828, None, None)),
+    (7692648, (24936, None, None)),
+    (7692651, (29291, None, None)),
+    (7692654, (24942, None, None)),
+    (7692912, (29552, None, None)),
+    (7693428, (7369828, None, None)),
+    (7693668, (25956, None, None)),
+    (7694435, (30051, None, None)),
+    (7694945, (6451818, None, None)),
+    (7695209, (30057, None, None)),
+    (7695728, (7497840, None, None)),
+    (7695733, (7103844, None, None)),
+    (7696739, (6845809, None, None)),
+    (7697785, (6780281, None, None)),
+    (7698542, (7368802, None, None)),
+    (7758183, (7759204, None, None)),
+    (7758186, (30314, None, None)),
+    (7758188, (30316, None, None)),
+    (7758190, (30318, None, None)),
+    (7758955, (6581114, None, None)),
+    (7759971, (30307, None, None)),
+    (7760228, (30308, None, None)),
+    (7760999, (30311, None, None)),
+    (7761011, (27763, None, None)),
+    (7762027, (30315, None, None)),
+    (7762536, (29288, None, None)),
+    (7763303, (8025444, None, None)),
+    (7825271, (6449015, None, None)),
+    (7825524, (6384751, None, None)),
+    (7825783, (7305070, None, None)),
+    (7826537, (7102823, None, None)),
+    (7826548, (7300471, None, None)),
+    (7828068, (24934, None, Some(17985))),
+    (7828339, (29555, None, None)),
+    (7889518, (6515557, None, None)),
+    (7890535, (6971766, None, None)),
+    (7890797, (7037546, None, None)),
+    (7891060, (6453615, None, None)),
+    (7892590, (7759726, None, None)),
+    (7895393, (7239022, None, None)),
+    (7954797, (29549, None, None)),
+    (7956857, (7172729, None, None)),
+    (7957874, (7171954, None, None)),
+    (7958371, (6973808, None, None)),
+    (7958643, (7236715, None, None)),
+    (7959142, (31078, None, None)),
+    (7959151, (29295, None, None)),
+    (7959152, (7631472, None, None)),
+    (8020322, (7304814, None, None)),
+    (8020327, (28015, None, None)),
+    (8020331, (27499, None, None)),
+    (8025196, (25196, None, None)),
+    (8025456, (6452592, None, None)),
+    (8025457, (30065, None, None)),
+];
+
+pub static SCRIPT_ALIASES: [(u32, u32); 1] = [(1767989585, 1752066394)];
+
+pub static TERRITORY_ALIASES: [(u32, &[u32]); 335] = [
+    (17232, &[19782, 18509, 20557, 22352]),
+    (17476, &[17732]),
+    (17494, &[20054]),
+    (17497, &[17753]),
+    (18510, &[21846]),
+    (18514, &[22362]),
+    (18765, &[19797]),
+    (19285, &[16967]),
+    (19287, &[19797]),
+    (20033, &[22339, 22611, 20802]),
+    (20564, &[19540]),
+    (20806, &[20801, 18004]),
+    (20814, &[20801]),
+    (21082, &[17475]),
+    (21315, &[21330, 17741]),
+    (21571, &[18763]),
+    (21578, &[19797]),
+    (21582, &[16723, 20809]),
+    (21826, &[19789]),
+    (21840, &[19797]),
+    (21841, &[21829]),
+    (
+        21843,
+        &[
+            21842, 19777, 23105, 22850, 17733, 17735, 23115, 18251, 22092, 21580, 17485, 19028,
+            19796, 16725, 23125,
+        ],
+    ),
+    (21849, &[21330, 17741]),
+    (22088, &[17986]),
+    (22598, &[21062]),
+    (22852, &[19010]),
+    (23120, &[16720]),
+    (3158065, &[18242]),
+    (3158066, &[23107, 19283]),
+    (3158067, &[21063]),
+    (3158068, &[20298]),
+    (3158069, &[21325]),
+    (3158070, &[22864]),
+    (3158072, &[18261]),
+    (3158320, &[20801]),
+    (3158324, &[21067]),
+    (3158327, &[16730]),
+    (
+        3158328,
+        &[
+            21842, 19777, 23105, 22850, 17733, 17735, 23115, 18251, 22092, 21580, 17485, 19028,
+            19796, 16725, 23125,
+        ],
+    ),
+    (3158576, &[17473]),
+    (3158577, &[19779]),
+    (3158579, &[21575]),
+    (3158581, &[21070]),
+    (3158582, &[21584]),
+    (3158583, &[17753]),
+    (3158834, &[21573]),
+    (3158836, &[21068]),
+    (3158837, &[22339, 22611, 20802]),
+    (3158838, &[21072]),
+    (3158840, &[17738, 18247]),
+    (3159088, &[21569]),
+    (3159089, &[17987]),
+    (3159091, &[20040]),
+    (3159092, &[21580]),
+    (3159093, &[17230]),
+    (3159095, &[21075]),
+    (3159096, &[21333]),
+    (3159344, &[17474]),
+    (3159346, &[21062]),
+    (3159348, &[18253]),
+    (3159352, &[18774]),
+    (3159600, &[19778]),
+    (3159602, &[18004]),
+    (3159603, &[17481]),
+    (3159606, &[18753]),
+    (3159607, &[22867]),
+    (3159608, &[23125]),
+    (3159609, &[20049]),
+    (3159856, &[16706]),
+    (3159857, &[20291]),
+    (3159858, &[19783]),
+    (3159860, &[21581]),
+    (3159861, &[21838]),
+    (3159862, &[17238]),
+    (3159865, &[22609]),
+    (3160113, &[17475]),
+    (3160114, &[17732]),
+    (3160115, &[21577]),
+    (3160116, &[21837]),
+    (3160117, &[20557]),
+    (3160119, &[21588]),
+    (3160121, &[18520]),
+    (3160368, &[16979]),
+    (3160374, &[17235]),
+    (3160376, &[21330, 17741, 18771, 21064, 19277, 16706]),
+    (3160377, &[21080]),
+    (3224368, &[23105]),
+    (3224370, &[21573]),
+    (3224373, &[22339]),
+    (3224376, &[18247]),
+    (3224880, &[19777]),
+    (3225401, &[22865]),
+    (3225653, &[19797]),
+    (3225657, &[18776]),
+    (3225905, &[21064]),
+    (3225909, &[16720]),
+    (3225912, &[21330, 17741]),
+    (3225913, &[21336]),
+    (3289143, &[18259]),
+    (3289392, &[23108]),
+    (3289393, &[22850]),
+    (3289394, &[19780]),
+    (3289395, &[20551]),
+    (3289397, &[19791]),
+    (3289398, &[20048]),
+    (3289650, &[22099]),
+    (3289652, &[16972]),
+    (3289904, &[21057]),
+    (3289905, &[22083]),
+    (3289906, &[21061]),
+    (3289907, &[21576]),
+    (3289909, &[22339, 22611, 20802]),
+    (3289911, &[18501]),
+    (3289912, &[17738]),
+    (3290162, &[19014]),
+    (3290164, &[21836]),
+    (3290166, &[20306]),
+    (3290416, &[16962]),
+    (3290417, &[19523]),
+    (3290419, &[21321]),
+    (3290422, &[19522]),
+    (3290423, &[17747]),
+    (3290672, &[3420976, 3355697]),
+    (3290673, &[22595]),
+    (3290674, &[19012]),
+    (3290676, &[22093]),
+    (3290677, &[17742]),
+    (3290678, &[17228]),
+    (3290679, &[19028]),
+    (3290680, &[17750]),
+    (3290681, &[20561]),
+    (3290928, &[22338]),
+    (
+        3290929,
+        &[
+            21842, 19777, 23105, 22850, 17735, 18251, 23115, 17485, 19028, 19796, 16725, 23125,
+        ],
+    ),
+    (3290931, &[17737]),
+    (3290935, &[19284]),
+    (3290937, &[23121]),
+    (3291189, &[19782, 18509, 20557, 22352]),
+    (3291190, &[16723]),
+    (3291192, &[21335]),
+    (3291193, &[19032]),
+    (3291440, &[18262]),
+    (3291441, &[21827]),
+    (3291442, &[18759]),
+    (3291443, &[20554]),
+    (3291444, &[17229]),
+    (3291447, &[21076]),
+    (3291449, &[21592]),
+    (3354674, &[23107]),
+    (3354679, &[19283]),
+    (3355442, &[17733]),
+    (3355445, &[22337]),
+    (3355448, &[19785]),
+    (3355702, &[21842]),
+    (3356214, &[17997]),
+    (3356217, &[20817]),
+    (3356473, &[16728]),
+    (3356725, &[19782]),
+    (3356729, &[19288]),
+    (3356985, &[21848]),
+    (3420208, &[17985]),
+    (3420209, &[19789]),
+    (3420210, &[19010]),
+    (3420211, &[19527]),
+    (3420212, &[17739]),
+    (3420213, &[16717]),
+    (3420214, &[17744]),
+    (3420215, &[20054]),
+    (3420216, &[16725]),
+    (3420466, &[20292]),
+    (3420468, &[22347]),
+    (3420720, &[20289]),
+    (3420721, &[16707]),
+    (3420723, &[20039]),
+    (3420725, &[20558]),
+    (3420726, &[22343]),
+    (3420727, &[21317]),
+    (3420978, &[20294]),
+    (3420979, &[19784]),
+    (3420980, &[22860]),
+    (3420981, &[22611]),
+    (3420982, &[16721]),
+    (3420984, &[23124]),
+    (3421232, &[21314]),
+    (3421233, &[19276]),
+    (3421235, &[19272]),
+    (3421239, &[19027]),
+    (3421490, &[17991]),
+    (3421492, &[22349]),
+    (3421493, &[23118]),
+    (3421494, &[18515]),
+    (3421496, &[17986]),
+    (3421744, &[21570]),
+    (3421747, &[21065]),
+    (3421751, &[18516]),
+    (3421753, &[21073]),
+    (3422000, &[22082]),
+    (3422001, &[19787]),
+    (3422004, &[20813]),
+    (3422005, &[17998]),
+    (3422006, &[19795]),
+    (3422009, &[16984]),
+    (3422256, &[23106]),
+    (3422257, &[19267]),
+    (3422259, &[18755]),
+    (3422260, &[22605]),
+    (3422261, &[18509]),
+    (3422263, &[17729]),
+    (3422265, &[19544]),
+    (3422518, &[19539]),
+    (3422520, &[19802]),
+    (3422521, &[22104]),
+    (3485751, &[18771]),
+    (3486517, &[20802]),
+    (3487289, &[21329]),
+    (3487537, &[21593]),
+    (3487538, &[21328]),
+    (3487545, &[17240]),
+    (3487797, &[22352]),
+    (3487801, &[19800]),
+    (3488055, &[19796]),
+    (3488057, &[22360]),
+    (3551287, &[20307]),
+    (3551536, &[21313]),
+    (3551537, &[18507]),
+    (3551539, &[21831]),
+    (3551541, &[16718]),
+    (3551542, &[19536]),
+    (3551543, &[22362]),
+    (3551794, &[20807]),
+    (3551796, &[21324]),
+    (3551798, &[19540]),
+    (3551800, &[16967]),
+    (3552048, &[21825]),
+    (3552049, &[22859]),
+    (3552051, &[16726]),
+    (3552053, &[16723, 20809]),
+    (3552055, &[17491]),
+    (3552306, &[18758]),
+    (3552308, &[20301]),
+    (3552310, &[22354]),
+    (3552560, &[17730]),
+    (3552561, &[20035]),
+    (3552563, &[20041]),
+    (3552567, &[18499]),
+    (3552817, &[17219]),
+    (3552818, &[16711]),
+    (3552820, &[19533]),
+    (3552821, &[18254]),
+    (3552822, &[19792]),
+    (3552825, &[21585]),
+    (3553072, &[21058]),
+    (3553074, &[17732]),
+    (3553075, &[19529]),
+    (3553079, &[20308]),
+    (3553080, &[18007]),
+    (3553081, &[17496]),
+    (3553328, &[20297]),
+    (3553333, &[19280]),
+    (3553334, &[20051]),
+    (3553336, &[17753]),
+    (3553337, &[20056]),
+    (3553584, &[20034]),
+    (3553585, &[22851]),
+    (3553586, &[18763]),
+    (3553588, &[20045]),
+    (3553591, &[17236]),
+    (3553593, &[22616]),
+    (3616824, &[19277]),
+    (3617076, &[18251]),
+    (3618361, &[21829]),
+    (3618617, &[17752]),
+    (3618872, &[17753]),
+    (3618873, &[20312]),
+    (3619129, &[22872]),
+    (3682352, &[19521]),
+    (3682353, &[18754]),
+    (3682354, &[19268]),
+    (3682355, &[17479]),
+    (3682356, &[20555]),
+    (3682357, &[23117]),
+    (3682358, &[18512]),
+    (3682610, &[17221]),
+    (3682612, &[16716]),
+    (3682616, &[18245]),
+    (3682864, &[18241]),
+    (3682867, &[22855]),
+    (3682868, &[22092]),
+    (3682869, &[19534]),
+    (3682871, &[21331]),
+    (3683122, &[19270]),
+    (3683124, &[18764]),
+    (3683126, &[17746]),
+    (3683376, &[18498]),
+    (3683377, &[17492]),
+    (3683378, &[22593]),
+    (3683379, &[21832]),
+    (3683381, &[21846]),
+    (3683383, &[23123]),
+    (3683633, &[22356]),
+    (3683634, &[18000]),
+    (3683636, &[22861]),
+    (3683637, &[18766]),
+    (3683640, &[22869]),
+    (3683641, &[16705]),
+    (3683888, &[20290]),
+    (3683890, &[17735]),
+    (3683891, &[20809]),
+    (3683895, &[18260]),
+    (3683897, &[22097]),
+    (3684145, &[18243]),
+    (3684146, &[17732]),
+    (3684148, &[21069]),
+    (3684149, &[20302]),
+    (3684150, &[21587]),
+    (3684153, &[18008]),
+    (3684401, &[21059]),
+    (3684402, &[18503]),
+    (3684403, &[19786]),
+    (3684406, &[21330]),
+    (3684407, &[20052]),
+    (3684409, &[20568]),
+    (3684659, &[23115]),
+    (3684660, &[17485]),
+    (3684661, &[18256]),
+    (3684663, &[22100]),
+    (3684665, &[23128]),
+    (3748407, &[17491]),
+    (3748658, &[21319]),
+    (3748914, &[21062]),
+    (3749174, &[20043]),
+    (3749177, &[19793]),
+    (3749433, &[22353]),
+    (3749689, &[18264]),
+    (3749945, &[20824]),
+    (3750196, &[17741]),
+    (3750201, &[23130]),
+];
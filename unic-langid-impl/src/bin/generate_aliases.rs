@@ -0,0 +1,120 @@
+//! Generates `src/aliases_table.rs` from `data/aliases.json`.
+//!
+//! The data follows the layout of `cldr-core/supplemental/aliases.json`.
+//! The vendored copy was extracted from the ICU 73.1 data files (CLDR 43),
+//! and keeps the `languageAlias`, `scriptAlias` and `territoryAlias` tables.
+//!
+//! Only aliases of a single language subtag are emitted, since
+//! `LanguageIdentifier::canonicalize_aliases` replaces subtags one at a time.
+//! Territory aliases which are not valid region subtags, such as the
+//! three-letter ISO 3166 codes, are skipped.
+use serde_json::Value;
+use std::fs;
+use unic_langid_impl::subtags::{Language, Region, Script};
+use unic_langid_impl::LanguageIdentifier;
+
+fn serialize_option<T: std::fmt::Display>(v: Option<T>) -> String {
+    if let Some(v) = v {
+        format!("Some({})", v)
+    } else {
+        String::from("None")
+    }
+}
+
+fn main() {
+    let contents =
+        fs::read_to_string("./data/aliases.json").expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    let values = &v["supplemental"]["metadata"]["alias"];
+
+    let mut language_aliases: Vec<(u64, String)> = vec![];
+    for (k, v) in values["languageAlias"].as_object().unwrap() {
+        if k.contains('-') {
+            continue;
+        }
+        let language: Language = k.parse().expect("Failed to parse a language.");
+        let replacement: LanguageIdentifier = v["_replacement"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .expect("Failed to parse a replacement.");
+        if replacement.variants().len() != 0 {
+            unimplemented!("Encountered a language replacement with variants!");
+        }
+        language_aliases.push((
+            language.as_u64().expect("Expected a language."),
+            format!(
+                "({}, {}, {})",
+                replacement
+                    .language
+                    .as_u64()
+                    .expect("Expected a replacement language."),
+                serialize_option(replacement.script.map(Script::as_u32)),
+                serialize_option(replacement.region.map(Region::as_u32)),
+            ),
+        ));
+    }
+    language_aliases.sort_by_key(|(language, _)| *language);
+
+    let mut script_aliases: Vec<(u32, u32)> = vec![];
+    for (k, v) in values["scriptAlias"].as_object().unwrap() {
+        let script: Script = k.parse().expect("Failed to parse a script.");
+        let replacement: Script = v["_replacement"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .expect("Failed to parse a replacement.");
+        script_aliases.push((script.as_u32(), replacement.as_u32()));
+    }
+    script_aliases.sort_unstable();
+
+    let mut territory_aliases: Vec<(u32, Vec<u32>)> = vec![];
+    for (k, v) in values["territoryAlias"].as_object().unwrap() {
+        let region: Region = match k.parse() {
+            Ok(region) => region,
+            Err(_) => continue,
+        };
+        let replacements = v["_replacement"]
+            .as_str()
+            .unwrap()
+            .split(' ')
+            .map(|r| {
+                let region: Region = r.parse().expect("Failed to parse a replacement.");
+                region.as_u32()
+            })
+            .collect();
+        territory_aliases.push((region.as_u32(), replacements));
+    }
+    territory_aliases.sort_by_key(|(region, _)| *region);
+
+    println!("#![allow(clippy::type_complexity)]");
+    println!("#![allow(clippy::unreadable_literal)]");
+    println!();
+    println!(
+        "pub static LANGUAGE_ALIASES: [(u64, (u64, Option<u32>, Option<u32>)); {}] = [",
+        language_aliases.len()
+    );
+    for (language, replacement) in language_aliases {
+        println!("    ({}, {}),", language, replacement);
+    }
+    println!("];");
+    println!();
+    println!(
+        "pub static SCRIPT_ALIASES: [(u32, u32); {}] = [",
+        script_aliases.len()
+    );
+    for (script, replacement) in script_aliases {
+        println!("    ({}, {}),", script, replacement);
+    }
+    println!("];");
+    println!();
+    println!(
+        "pub static TERRITORY_ALIASES: [(u32, &[u32]); {}] = [",
+        territory_aliases.len()
+    );
+    for (region, replacements) in territory_aliases {
+        let replacements: Vec<String> = replacements.iter().map(|r| r.to_string()).collect();
+        println!("    ({}, &[{}]),", region, replacements.join(", "));
+    }
+    println!("];");
+}
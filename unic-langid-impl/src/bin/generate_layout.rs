@@ -6,6 +6,24 @@ use unic_langid_impl::subtags::{Language, Script};
 use unic_langid_impl::CharacterDirection;
 use unic_langid_impl::LanguageIdentifier;
 
+// Scripts written right-to-left according to CLDR `scriptMetadata`.
+//
+// The layout data only covers scripts that have a locale of their own, so
+// the `rtl` flag of `scriptMetadata` makes sure that an explicit script
+// subtag such as `Adlm` or `Hebr` is recognized even when no locale in CLDR
+// uses it.
+fn scripts_rtl(path: &str) -> Vec<Script> {
+    let contents = fs::read_to_string(path).expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    v["scriptMetadata"]
+        .as_object()
+        .unwrap()
+        .iter()
+        .filter(|(_, v)| v["rtl"].as_str() == Some("YES"))
+        .map(|(k, _)| k.parse().expect("Failed to parse a script."))
+        .collect()
+}
+
 fn langid_to_direction_map(path: &str) -> HashMap<LanguageIdentifier, CharacterDirection> {
     let mut result = HashMap::new();
     for entry in fs::read_dir(path).unwrap() {
@@ -15,7 +33,7 @@ fn langid_to_direction_map(path: &str) -> HashMap<LanguageIdentifier, CharacterD
         let contents = fs::read_to_string(path).expect("Something went wrong reading the file");
         let v: Value = serde_json::from_str(&contents).unwrap();
 
-        let langid_key = v["main"].as_object().unwrap().keys().nth(0).unwrap();
+        let langid_key = v["main"].as_object().unwrap().keys().next().unwrap();
 
         if langid_key == "root" {
             continue;
@@ -59,6 +77,16 @@ fn check_all_variants_rtl(
     true
 }
 
+fn cldr_version(path: &str) -> String {
+    let contents = fs::read_to_string(format!("{}root/layout.json", path))
+        .expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    v["main"]["root"]["identity"]["version"]["_cldrVersion"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
 fn main() {
     let path = "./data/cldr-misc-full/main/";
     let map = langid_to_direction_map(path);
@@ -98,6 +126,8 @@ fn main() {
         langs.insert(lang);
     }
 
+    scripts.extend(scripts_rtl("./data/scriptMetadata.json"));
+
     let mut scripts: Vec<String> = scripts
         .into_iter()
         .map(|s| {
@@ -106,6 +136,8 @@ fn main() {
         })
         .collect();
     scripts.sort();
+    let mut lang_names: Vec<String> = langs.iter().map(|l| format!("{:?}", l.as_str())).collect();
+    lang_names.sort();
     let mut langs: Vec<String> = langs
         .into_iter()
         .map(|s| {
@@ -116,6 +148,11 @@ fn main() {
         .collect();
     langs.sort();
 
+    println!(
+        "pub static CLDR_VERSION: &str = \"{}\";",
+        cldr_version(path)
+    );
+
     println!(
         "pub const SCRIPTS_CHARACTER_DIRECTION_RTL: [u32; {}] = [{}];",
         scripts.len(),
@@ -127,4 +164,10 @@ fn main() {
         langs.len(),
         langs.join(", ")
     );
+
+    println!(
+        "pub const LANGS_CHARACTER_DIRECTION_RTL_STR: [&str; {}] = [{}];",
+        lang_names.len(),
+        lang_names.join(", ")
+    );
 }
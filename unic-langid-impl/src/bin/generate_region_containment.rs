@@ -0,0 +1,65 @@
+//! Generates `src/region_containment.rs` from `data/territoryContainment.json`.
+//!
+//! The data follows the layout of
+//! `cldr-core/supplemental/territoryContainment.json`. The vendored copy was
+//! extracted from the ICU 73.1 data files (CLDR 43).
+//!
+//! Deprecated containment (`-status-deprecated`) is skipped, numeric
+//! groupings such as `419` are kept, and non-geographic groupings such as
+//! `EU`, `EZ` or `UN` are left out both as containers and as children.
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use unic_langid_impl::subtags::Region;
+
+fn is_alphabetic_grouping(values: &serde_json::Map<String, Value>, region: &str) -> bool {
+    values
+        .get(region)
+        .is_some_and(|v| v["_grouping"].as_str() == Some("true"))
+        && region.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn main() {
+    let contents = fs::read_to_string("./data/territoryContainment.json")
+        .expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    let values = v["supplemental"]["territoryContainment"]
+        .as_object()
+        .unwrap();
+
+    let mut containment: BTreeMap<u32, Vec<Region>> = BTreeMap::new();
+
+    for (k, v) in values {
+        let key = match k.split_once("-status-") {
+            None => k.as_str(),
+            Some((key, "grouping")) => key,
+            Some((_, "deprecated")) => continue,
+            Some(_) => unimplemented!("Encountered unknown containment status!"),
+        };
+        if is_alphabetic_grouping(values, key) {
+            continue;
+        }
+        let region: Region = key.parse().expect("Failed to parse a region.");
+        let children = containment.entry(region.as_u32()).or_default();
+        for child in v["_contains"].as_array().unwrap() {
+            let child = child.as_str().unwrap();
+            if is_alphabetic_grouping(values, child) {
+                continue;
+            }
+            children.push(child.parse().expect("Failed to parse a region."));
+        }
+    }
+
+    println!("#![allow(clippy::unreadable_literal)]");
+    println!();
+    println!(
+        "pub static REGION_CONTAINMENT: [(u32, &[u32]); {}] = [",
+        containment.len()
+    );
+    for (region, mut children) in containment {
+        children.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let children: Vec<String> = children.iter().map(|r| r.as_u32().to_string()).collect();
+        println!("    ({}, &[{}]),", region, children.join(", "));
+    }
+    println!("];");
+}
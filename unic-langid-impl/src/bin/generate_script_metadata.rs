@@ -0,0 +1,82 @@
+//! Generates `src/script_metadata_table.rs` from `data/scriptMetadata.json`.
+//!
+//! The data follows the layout of `cldr-core/supplemental/scriptMetadata.json`.
+//! The vendored copy was extracted from the ICU 73.1 data files (CLDR 43),
+//! which keep the `sampleChar`, `idUsage`, `rtl`, `lbLetters` and `hasCase`
+//! fields of CLDR `scriptMetadata`. The `rank`, `shapingReq`, `originCountry`
+//! and `likelyLanguage` fields are emitted whenever the data provides them.
+use serde_json::Value;
+use std::fs;
+use unic_langid_impl::subtags::{Language, Region, Script};
+
+fn serialize_option<T: std::fmt::Display>(v: Option<T>) -> String {
+    if let Some(v) = v {
+        format!("Some({})", v)
+    } else {
+        String::from("None")
+    }
+}
+
+fn parse_flag(v: &Value) -> Option<bool> {
+    match v.as_str() {
+        Some("YES") => Some(true),
+        Some("NO") => Some(false),
+        Some("UNKNOWN") | None => None,
+        _ => unimplemented!("Encountered unknown flag value!"),
+    }
+}
+
+fn main() {
+    let contents = fs::read_to_string("./data/scriptMetadata.json")
+        .expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    let values = v["scriptMetadata"].as_object().unwrap();
+
+    let mut entries: Vec<(u32, String)> = vec![];
+
+    for (k, v) in values {
+        let script: Script = k.parse().expect("Failed to parse a script.");
+        let sample_char = v["sampleChar"]
+            .as_str()
+            .and_then(|s| s.chars().next())
+            .expect("Expected a sample character.");
+        let origin_country = v["originCountry"].as_str().map(|r| {
+            let region: Region = r.parse().expect("Failed to parse an origin country.");
+            region.as_u32()
+        });
+        let likely_language = v["likelyLanguage"].as_str().and_then(|l| {
+            let lang: Language = l.parse().expect("Failed to parse a likely language.");
+            lang.as_u64()
+        });
+
+        let script: u32 = script.into();
+        entries.push((
+            script,
+            format!(
+                "({}, {}, {:?}, {}, {}, {}, {}, {})",
+                script,
+                serialize_option(v["rank"].as_u64()),
+                sample_char,
+                parse_flag(&v["rtl"]).expect("Expected an rtl flag."),
+                serialize_option(parse_flag(&v["shapingReq"])),
+                parse_flag(&v["hasCase"]).unwrap_or(false),
+                serialize_option(origin_country),
+                serialize_option(likely_language),
+            ),
+        ));
+    }
+
+    entries.sort_by_key(|(script, _)| *script);
+
+    println!("#![allow(clippy::type_complexity)]");
+    println!("#![allow(clippy::unreadable_literal)]");
+    println!();
+    println!(
+        "pub static SCRIPT_METADATA: [(u32, Option<u32>, char, bool, Option<bool>, bool, Option<u32>, Option<u64>); {}] = [",
+        entries.len()
+    );
+    for (_, entry) in entries {
+        println!("    {},", entry);
+    }
+    println!("];");
+}
@@ -0,0 +1,88 @@
+use crate::errors::LanguageIdentifierError;
+use crate::subtags;
+use crate::LanguageIdentifier;
+use alloc::vec::Vec;
+
+/// A builder for constructing a `LanguageIdentifier` from individual subtags.
+///
+/// Every setter validates its subtag immediately. The first error
+/// encountered is returned from `build`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+///
+/// let li = LanguageIdentifier::builder()
+///     .language("en")
+///     .script("Latn")
+///     .region("US")
+///     .variant("posix")
+///     .build()
+///     .expect("Building failed.");
+///
+/// assert_eq!(li.to_string(), "en-Latn-US-posix");
+/// ```
+#[derive(Debug, Default)]
+pub struct LanguageIdentifierBuilder {
+    langid: LanguageIdentifier,
+    variants: Vec<subtags::Variant>,
+    error: Option<LanguageIdentifierError>,
+}
+
+impl LanguageIdentifierBuilder {
+    /// Sets the language subtag.
+    pub fn language<S: AsRef<[u8]>>(mut self, language: S) -> Self {
+        match subtags::Language::from_bytes(language.as_ref()) {
+            Ok(language) => self.langid.language = language,
+            Err(err) => self.set_error(err.into()),
+        }
+        self
+    }
+
+    /// Sets the script subtag.
+    pub fn script<S: AsRef<[u8]>>(mut self, script: S) -> Self {
+        match subtags::Script::from_bytes(script.as_ref()) {
+            Ok(script) => self.langid.script = Some(script),
+            Err(err) => self.set_error(err.into()),
+        }
+        self
+    }
+
+    /// Sets the region subtag.
+    pub fn region<S: AsRef<[u8]>>(mut self, region: S) -> Self {
+        match subtags::Region::from_bytes(region.as_ref()) {
+            Ok(region) => self.langid.region = Some(region),
+            Err(err) => self.set_error(err.into()),
+        }
+        self
+    }
+
+    /// Adds a variant subtag.
+    pub fn variant<S: AsRef<[u8]>>(mut self, variant: S) -> Self {
+        match subtags::Variant::from_bytes(variant.as_ref()) {
+            Ok(variant) => self.variants.push(variant),
+            Err(err) => self.set_error(err.into()),
+        }
+        self
+    }
+
+    /// Produces the `LanguageIdentifier`, returning the first error
+    /// encountered by any of the setters.
+    ///
+    /// Duplicate variants result in an error.
+    pub fn build(self) -> Result<LanguageIdentifier, LanguageIdentifierError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let mut langid = self.langid;
+        langid.set_unique_variants(self.variants)?;
+        Ok(langid)
+    }
+
+    fn set_error(&mut self, err: LanguageIdentifierError) {
+        if self.error.is_none() {
+            self.error = Some(err);
+        }
+    }
+}
@@ -1,6 +1,5 @@
 use super::parser::ParserError;
-use std::error::Error;
-use std::fmt::{self, Display};
+use core::fmt::{self, Display};
 
 /// Enum with errors that can be returned by LanguageIdentifier.
 #[derive(Debug, PartialEq)]
@@ -9,6 +8,8 @@ pub enum LanguageIdentifierError {
     Unknown,
     /// A parser error.
     ParserError(ParserError),
+    /// The variants are not sorted, as reported by `validate`.
+    UnsortedVariants,
 }
 
 impl From<ParserError> for LanguageIdentifierError {
@@ -17,13 +18,56 @@ impl From<ParserError> for LanguageIdentifierError {
     }
 }
 
-impl Error for LanguageIdentifierError {}
+#[cfg(feature = "std")]
+impl std::error::Error for LanguageIdentifierError {}
 
 impl Display for LanguageIdentifierError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             LanguageIdentifierError::Unknown => write!(f, "Unknown error"),
             LanguageIdentifierError::ParserError(p) => write!(f, "Parser error: {}", p),
+            LanguageIdentifierError::UnsortedVariants => write!(f, "Variants are not sorted"),
+        }
+    }
+}
+
+/// Enum with errors that can be returned when parsing a `CharacterDirection`.
+#[derive(Debug, PartialEq)]
+pub enum CharacterDirectionError {
+    /// The string is neither `ltr` nor `rtl`.
+    InvalidDirection,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CharacterDirectionError {}
+
+impl Display for CharacterDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CharacterDirectionError::InvalidDirection => write!(f, "Invalid character direction"),
+        }
+    }
+}
+
+/// Enum with errors that can be returned by likely subtags operations.
+#[cfg(feature = "likelysubtags")]
+#[derive(Debug, PartialEq)]
+pub enum LikelySubtagsError {
+    /// No likely subtags data is available for the given subtags.
+    NoData,
+    /// The likely subtags tables are not sorted or hold malformed subtags.
+    InvalidData,
+}
+
+#[cfg(all(feature = "likelysubtags", feature = "std"))]
+impl std::error::Error for LikelySubtagsError {}
+
+#[cfg(feature = "likelysubtags")]
+impl Display for LikelySubtagsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LikelySubtagsError::NoData => write!(f, "No likely subtags data available"),
+            LikelySubtagsError::InvalidData => write!(f, "Invalid likely subtags data"),
         }
     }
 }
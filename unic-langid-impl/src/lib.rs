@@ -1,17 +1,51 @@
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod accept_language;
+mod aliases;
+mod aliases_table;
+mod builder;
 mod errors;
+#[cfg(feature = "direction")]
 mod layout_table;
 #[cfg(feature = "likelysubtags")]
 pub mod likelysubtags;
+#[cfg(feature = "likelysubtags")]
+mod negotiate;
+mod parsed;
 #[doc(hidden)]
 pub mod parser;
+mod region_containment;
+#[cfg(feature = "direction")]
+mod script_metadata_table;
 #[cfg(feature = "serde")]
 mod serde;
 pub mod subtags;
 
-pub use crate::errors::LanguageIdentifierError;
-use std::fmt::Write;
-use std::iter::Peekable;
-use std::str::FromStr;
+pub use crate::accept_language::parse_accept_language;
+pub use crate::builder::LanguageIdentifierBuilder;
+#[cfg(feature = "likelysubtags")]
+pub use crate::errors::LikelySubtagsError;
+pub use crate::errors::{CharacterDirectionError, LanguageIdentifierError};
+/// The CLDR version of the embedded character direction data.
+///
+/// The likely subtags data reports its own version in `likelysubtags::CLDR_VERSION`.
+#[cfg(feature = "direction")]
+pub use crate::layout_table::CLDR_VERSION;
+#[cfg(feature = "likelysubtags")]
+pub use crate::negotiate::{negotiate_languages, NegotiationStrategy};
+pub use crate::parsed::{ParsedLanguageIdentifier, ParserOptions};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt::Write;
+use core::iter::Peekable;
+use core::str::FromStr;
 
 /// Enum representing available character direction orientations.
 #[derive(Debug, PartialEq)]
@@ -26,6 +60,42 @@ pub enum CharacterDirection {
     LTR,
 }
 
+/// Parses `ltr` or `rtl`, ignoring ASCII casing.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::CharacterDirection;
+///
+/// let dir: CharacterDirection = "RTL".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(dir, CharacterDirection::RTL);
+/// assert_eq!(dir.to_string(), "rtl");
+/// ```
+impl FromStr for CharacterDirection {
+    type Err = CharacterDirectionError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        if source.eq_ignore_ascii_case("ltr") {
+            Ok(Self::LTR)
+        } else if source.eq_ignore_ascii_case("rtl") {
+            Ok(Self::RTL)
+        } else {
+            Err(CharacterDirectionError::InvalidDirection)
+        }
+    }
+}
+
+impl core::fmt::Display for CharacterDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::RTL => f.write_str("rtl"),
+            Self::LTR => f.write_str("ltr"),
+        }
+    }
+}
+
 type PartsTuple = (
     subtags::Language,
     Option<subtags::Script>,
@@ -76,7 +146,25 @@ type PartsTuple = (
 /// assert_eq!(li.region.as_ref().map(Into::into), Some("US"));
 /// assert_eq!(li.variants().map(|v| v.as_str()).collect::<Vec<_>>(), &["valencia"]);
 /// ```
-#[derive(Default, Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+///
+/// # Ordering
+///
+/// `LanguageIdentifier`s are ordered by their canonical subtags compared
+/// lexically: language, then script, then region, then variants. This
+/// matches the order of their canonical string representations, regardless
+/// of the casing or separators used in the parsed input.
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+///
+/// let li1: LanguageIdentifier = "EN_latn".parse()
+///     .expect("Parsing failed.");
+/// let li2: LanguageIdentifier = "en-US".parse()
+///     .expect("Parsing failed.");
+///
+/// assert!(li1 < li2);
+/// ```
+#[derive(Default, Debug, PartialEq, Eq, Hash)]
 pub struct LanguageIdentifier {
     pub language: subtags::Language,
     pub script: Option<subtags::Script>,
@@ -85,9 +173,12 @@ pub struct LanguageIdentifier {
 }
 
 impl LanguageIdentifier {
-    /// A constructor which takes a utf8 slice, parses it and
+    /// A constructor which takes a byte slice, parses it and
     /// produces a well-formed `LanguageIdentifier`.
     ///
+    /// The bytes don't have to be validated as UTF-8 first. Only ASCII is
+    /// valid in BCP 47 subtags, so any other byte results in an error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -97,13 +188,133 @@ impl LanguageIdentifier {
     ///     .expect("Parsing failed.");
     ///
     /// assert_eq!(li.to_string(), "en-US");
+    ///
+    /// assert!(LanguageIdentifier::from_bytes(b"en-\xff\xfe").is_err());
+    /// ```
+    ///
+    /// BCP 47 grandfathered tags, such as `i-klingon` or `zh-min-nan`, are
+    /// replaced with their preferred values. Grandfathered tags without a
+    /// preferred value, such as `i-default`, are parsed like any other input,
+    /// which usually rejects them.
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li = LanguageIdentifier::from_bytes(b"i-klingon")
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_string(), "tlh");
+    /// assert!(LanguageIdentifier::from_bytes(b"i-default").is_err());
     /// ```
     pub fn from_bytes(v: &[u8]) -> Result<Self, LanguageIdentifierError> {
         Ok(parser::parse_language_identifier(v)?)
     }
 
-    /// A constructor which takes optional subtags as `AsRef<[u8]>`, parses them and
-    /// produces a well-formed `LanguageIdentifier`.
+    /// Parses `input` into a canonical `LanguageIdentifier`, keeping a
+    /// serialization of the input controlled by `options`.
+    ///
+    /// The returned `ParsedLanguageIdentifier` dereferences to the canonical
+    /// `LanguageIdentifier`, while its `Display` implementation can preserve
+    /// the casing and variant order of the input. With the default options
+    /// it serializes the same way as the `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+    ///
+    /// let options = ParserOptions {
+    ///     normalize_case: false,
+    ///     ..ParserOptions::default()
+    /// };
+    /// let li = LanguageIdentifier::parse_with_options("EN-latn-us", options)
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_string(), "EN-latn-us");
+    /// assert_eq!(li.script.unwrap(), "Latn");
+    /// assert_eq!(li.into_langid().to_string(), "en-Latn-US");
+    /// ```
+    pub fn parse_with_options(
+        input: &str,
+        options: ParserOptions,
+    ) -> Result<ParsedLanguageIdentifier, LanguageIdentifierError> {
+        ParsedLanguageIdentifier::parse(input, options)
+    }
+
+    /// Returns `true` if `input` is a well-formed language identifier.
+    ///
+    /// Only the structure of the input is checked: the number, length and
+    /// characters of the subtags and the absence of duplicate variants.
+    /// Subtags are not looked up in any registry, so unknown but well-formed
+    /// identifiers are accepted.
+    ///
+    /// This runs the same parser as `from_str` and discards the result, so
+    /// both always agree on which inputs are well-formed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// assert!(LanguageIdentifier::is_well_formed("qaa-Qaaa-QM"));
+    /// assert!(LanguageIdentifier::is_well_formed("EN_us"));
+    /// assert!(!LanguageIdentifier::is_well_formed("en-US-"));
+    /// assert!(!LanguageIdentifier::is_well_formed("en-posix-POSIX"));
+    /// ```
+    pub fn is_well_formed(input: &str) -> bool {
+        parser::parse_language_identifier(input.as_bytes()).is_ok()
+    }
+
+    /// Parses `input` like `from_str`, but ignores trailing separators.
+    ///
+    /// This is useful to validate input which is still being typed, such
+    /// as `en-`. Empty subtags anywhere else are still rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li = LanguageIdentifier::parse_lenient("en-US-")
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_string(), "en-US");
+    /// assert!(LanguageIdentifier::parse_lenient("en--US").is_err());
+    /// assert!("en-US-".parse::<LanguageIdentifier>().is_err());
+    /// ```
+    pub fn parse_lenient(input: &str) -> Result<Self, LanguageIdentifierError> {
+        Self::from_bytes(input.trim_end_matches(['-', '_']).as_bytes())
+    }
+
+    /// Parses every string produced by the iterator, returning one result
+    /// per input in the same order.
+    ///
+    /// A malformed input does not prevent the remaining ones from being parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let results = LanguageIdentifier::parse_all(vec!["en-US", "x-bogus", "fr"]);
+    ///
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(results[0].as_ref().map(|li| li.to_string()), Ok("en-US".to_string()));
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results[2].as_ref().map(|li| li.to_string()), Ok("fr".to_string()));
+    /// ```
+    pub fn parse_all<'a, I: IntoIterator<Item = &'a str>>(
+        iter: I,
+    ) -> Vec<Result<Self, LanguageIdentifierError>> {
+        iter.into_iter()
+            .map(|s| Self::from_bytes(s.as_bytes()))
+            .collect()
+    }
+
+    /// A constructor which takes already parsed subtags and produces
+    /// a well-formed `LanguageIdentifier` without reparsing them.
+    ///
+    /// Variants are sorted and deduplicated.
     ///
     /// # Examples
     ///
@@ -142,10 +353,30 @@ impl LanguageIdentifier {
         }
     }
 
+    /// Returns a `LanguageIdentifierBuilder` for constructing
+    /// a `LanguageIdentifier` subtag by subtag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li = LanguageIdentifier::builder()
+    ///     .language("fr")
+    ///     .region("CA")
+    ///     .build()
+    ///     .expect("Building failed.");
+    ///
+    /// assert_eq!(li.to_string(), "fr-CA");
+    /// ```
+    pub fn builder() -> LanguageIdentifierBuilder {
+        LanguageIdentifierBuilder::default()
+    }
+
     /// # Unchecked
     ///
     /// This function accepts subtags expecting variants
-    /// to be deduplicated and ordered.
+    /// to be deduplicated and ordered. Use `validate` to check them.
     pub const fn from_raw_parts_unchecked(
         language: subtags::Language,
         script: Option<subtags::Script>,
@@ -160,6 +391,46 @@ impl LanguageIdentifier {
         }
     }
 
+    /// Checks that the variants of the `LanguageIdentifier` are sorted and
+    /// deduplicated, as they are after parsing.
+    ///
+    /// Identifiers constructed with `from_raw_parts_unchecked` may break this
+    /// invariant, which makes comparisons and variant lookups incorrect. A
+    /// duplicate variant results in `ParserError::DuplicateVariant`, and
+    /// unsorted variants in `LanguageIdentifierError::UnsortedVariants`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
+    /// use unic_langid_impl::subtags::{Language, Variant};
+    ///
+    /// let macos: Variant = "macos".parse()
+    ///     .expect("Parsing failed.");
+    /// let posix: Variant = "posix".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let li = LanguageIdentifier::from_raw_parts_unchecked(
+    ///     Language::default(), None, None, Some(vec![posix, macos].into_boxed_slice()));
+    /// assert_eq!(li.validate(), Err(LanguageIdentifierError::UnsortedVariants));
+    ///
+    /// let li = LanguageIdentifier::from_raw_parts_unchecked(
+    ///     Language::default(), None, None, Some(vec![macos, posix].into_boxed_slice()));
+    /// assert_eq!(li.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), LanguageIdentifierError> {
+        let variants: &[_] = self.variants.as_deref().unwrap_or(&[]);
+        for w in variants.windows(2) {
+            if w[0] == w[1] {
+                return Err(parser::ParserError::DuplicateVariant(w[0]).into());
+            }
+            if w[0] > w[1] {
+                return Err(LanguageIdentifierError::UnsortedVariants);
+            }
+        }
+        Ok(())
+    }
+
     #[doc(hidden)]
     /// This method is used by `unic-locale` to handle partial
     /// subtag iterator.
@@ -210,6 +481,87 @@ impl LanguageIdentifier {
         )
     }
 
+    /// Consumes `LanguageIdentifier` and produces the integer encoding of all
+    /// of its subtags, which can be restored with `from_raw_parts`.
+    ///
+    /// Each subtag is encoded as its canonically cased ASCII bytes read as
+    /// a little-endian integer, padded with zero bytes: `u64` for the language
+    /// and variants, `u32` for the script and region. An undefined language
+    /// is encoded as `None`. This encoding is stable across releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let (lang, script, region, variants) = li.clone().into_raw_parts();
+    /// assert_eq!(lang, Some(u64::from_le_bytes(*b"en\0\0\0\0\0\0")));
+    /// assert_eq!(region, Some(u32::from_le_bytes(*b"US\0\0")));
+    ///
+    /// let li2 = LanguageIdentifier::from_raw_parts(lang, script, region, &variants)
+    ///     .expect("Decoding failed.");
+    /// assert_eq!(li, li2);
+    /// ```
+    pub fn into_raw_parts(self) -> (Option<u64>, Option<u32>, Option<u32>, Vec<u64>) {
+        (
+            self.language.into(),
+            self.script.map(Into::into),
+            self.region.map(Into::into),
+            self.variants().map(Into::into).collect(),
+        )
+    }
+
+    /// A constructor which takes the integer encoding produced by
+    /// `into_raw_parts` and produces a well-formed `LanguageIdentifier`.
+    ///
+    /// Every subtag is validated, so malformed or duplicate subtags result
+    /// in an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li = LanguageIdentifier::from_raw_parts(
+    ///     Some(u64::from_le_bytes(*b"sr\0\0\0\0\0\0")),
+    ///     Some(u32::from_le_bytes(*b"Cyrl")),
+    ///     None,
+    ///     &[],
+    /// ).expect("Decoding failed.");
+    /// assert_eq!(li.to_string(), "sr-Cyrl");
+    ///
+    /// assert!(LanguageIdentifier::from_raw_parts(Some(0), None, None, &[]).is_err());
+    /// ```
+    pub fn from_raw_parts(
+        language: Option<u64>,
+        script: Option<u32>,
+        region: Option<u32>,
+        variants: &[u64],
+    ) -> Result<Self, LanguageIdentifierError> {
+        let mut langid = Self {
+            language: match language {
+                Some(l) => subtags::Language::from_bytes(raw_bytes(&l.to_le_bytes()))?,
+                None => subtags::Language::default(),
+            },
+            script: script
+                .map(|s| subtags::Script::from_bytes(raw_bytes(&s.to_le_bytes())))
+                .transpose()?,
+            region: region
+                .map(|r| subtags::Region::from_bytes(raw_bytes(&r.to_le_bytes())))
+                .transpose()?,
+            variants: None,
+        };
+        let variants = variants
+            .iter()
+            .map(|v| subtags::Variant::from_bytes(raw_bytes(&v.to_le_bytes())))
+            .collect::<Result<Vec<_>, _>>()?;
+        langid.set_unique_variants(variants)?;
+        Ok(langid)
+    }
+
     /// Compares a `LanguageIdentifier` to another `AsRef<LanguageIdentifier`
     /// allowing for either side to use the missing fields as wildcards.
     ///
@@ -242,7 +594,7 @@ impl LanguageIdentifier {
     ) -> bool {
         let other = other.as_ref();
         self.language
-            .matches(&other.language, self_as_range, other_as_range)
+            .matches(other.language, self_as_range, other_as_range)
             && subtag_matches(&self.script, &other.script, self_as_range, other_as_range)
             && subtag_matches(&self.region, &other.region, self_as_range, other_as_range)
             && subtags_match(
@@ -253,184 +605,1236 @@ impl LanguageIdentifier {
             )
     }
 
-    /// Returns a vector of variants subtags of the `LanguageIdentifier`.
+    /// Returns `true` if the `LanguageIdentifier` is at least as specific as `other`,
+    /// which means every subtag defined in `other` is also present in `self`.
+    ///
+    /// Unlike `matches`, the check is directional: subtags missing from `self`
+    /// are never treated as wildcards.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_langid_impl::LanguageIdentifier;
     ///
-    /// let li1: LanguageIdentifier = "ca-ES-valencia".parse()
+    /// let li1: LanguageIdentifier = "en-Latn-US".parse()
     ///     .expect("Parsing failed.");
-    ///
-    /// assert_eq!(li1.variants().map(|v| v.as_str()).collect::<Vec<_>>(), &["valencia"]);
-    ///
-    /// let li2: LanguageIdentifier = "de".parse()
+    /// let li2: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "en".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// assert_eq!(li2.variants().len(), 0);
+    /// assert!(li1.includes(&li2));
+    /// assert!(li2.includes(&li3));
+    /// assert!(!li3.includes(&li2));
     /// ```
-    pub fn variants(&self) -> impl ExactSizeIterator<Item = &subtags::Variant> {
-        let variants: &[_] = match self.variants {
-            Some(ref v) => &**v,
-            None => &[],
-        };
-
-        variants.iter()
+    pub fn includes(&self, other: &Self) -> bool {
+        (other.language.is_empty() || self.language == other.language)
+            && (other.script.is_none() || self.script == other.script)
+            && (other.region.is_none() || self.region == other.region)
+            && other.variants().all(|v| self.has_variant(*v))
     }
 
-    /// Sets variant subtags of the `LanguageIdentifier`.
+    /// Returns `true` if all subtags of the `LanguageIdentifier` are undefined.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_langid_impl::LanguageIdentifier;
     ///
-    /// let mut li: LanguageIdentifier = "ca-ES".parse()
+    /// let li1: LanguageIdentifier = "und".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// li.set_variants(&["valencia".parse().expect("Parsing failed.")]);
+    /// assert_eq!(li1.is_empty(), true);
     ///
-    /// assert_eq!(li.to_string(), "ca-ES-valencia");
+    /// let li2: LanguageIdentifier = "und-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li2.is_empty(), false);
     /// ```
-    pub fn set_variants(&mut self, variants: &[subtags::Variant]) {
-        let mut v = variants.to_vec();
-
-        if v.is_empty() {
-            self.variants = None;
-        } else {
-            v.sort_unstable();
-            v.dedup();
-            self.variants = Some(v.into_boxed_slice());
-        }
+    pub fn is_empty(&self) -> bool {
+        self.language.is_empty()
+            && self.script.is_none()
+            && self.region.is_none()
+            && self.variants.is_none()
     }
 
-    /// Tests if a variant subtag is present in the `LanguageIdentifier`.
+    /// Returns `true` if the `LanguageIdentifier` has no script, region or
+    /// variants, regardless of whether the language is defined.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_langid_impl::LanguageIdentifier;
     ///
-    /// let mut li: LanguageIdentifier = "ca-ES-macos".parse()
+    /// let li1: LanguageIdentifier = "en".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// assert_eq!(li.has_variant("valencia".parse().unwrap()), false);
-    /// assert_eq!(li.has_variant("macos".parse().unwrap()), true);
+    /// assert_eq!(li1.is_language_only(), true);
+    ///
+    /// let li2: LanguageIdentifier = "und-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li2.is_language_only(), false);
     /// ```
-    pub fn has_variant(&self, variant: subtags::Variant) -> bool {
-        if let Some(variants) = &self.variants {
-            variants.contains(&variant)
-        } else {
-            false
-        }
+    pub fn is_language_only(&self) -> bool {
+        self.script.is_none() && self.region.is_none() && self.variants.is_none()
     }
 
-    /// Clears variant subtags of the `LanguageIdentifier`.
+    /// Parses and sets the region subtag of the `LanguageIdentifier`.
+    ///
+    /// Both two letter ISO 3166-1 codes and three digit UN M.49 codes
+    /// are accepted. A malformed region results in
+    /// `ParserError::InvalidRegion` and leaves the region unchanged.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_langid_impl::LanguageIdentifier;
     ///
-    /// let mut li: LanguageIdentifier = "ca-ES-valencia".parse()
+    /// let mut li: LanguageIdentifier = "es".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// li.clear_variants();
+    /// li.set_region("419")
+    ///     .expect("Setting region failed.");
+    /// assert_eq!(li.to_string(), "es-419");
     ///
-    /// assert_eq!(li.to_string(), "ca-ES");
+    /// li.set_region("mx")
+    ///     .expect("Setting region failed.");
+    /// assert_eq!(li.to_string(), "es-MX");
+    ///
+    /// assert!(li.set_region("12").is_err());
+    /// assert_eq!(li.to_string(), "es-MX");
     /// ```
-    pub fn clear_variants(&mut self) {
-        self.variants = None;
+    pub fn set_region<S: AsRef<[u8]>>(&mut self, region: S) -> Result<(), LanguageIdentifierError> {
+        self.region = Some(subtags::Region::from_bytes(region.as_ref())?);
+        Ok(())
     }
 
-    /// Extends the `LanguageIdentifier` adding likely subtags based
-    /// on tables provided by CLDR.
+    /// Consumes the `LanguageIdentifier` and returns it with the language
+    /// subtag replaced.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_langid_impl::LanguageIdentifier;
     ///
-    /// let mut li: LanguageIdentifier = "en-US".parse()
+    /// let li: LanguageIdentifier = "en-US".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// assert_eq!(li.maximize(), true);
-    /// assert_eq!(li.to_string(), "en-Latn-US");
+    /// let li = li.with_language("es")
+    ///     .expect("Setting language failed.");
+    /// assert_eq!(li.to_string(), "es-US");
     /// ```
-    #[cfg(feature = "likelysubtags")]
-    pub fn maximize(&mut self) -> bool {
-        if let Some(new_li) = likelysubtags::maximize(self.language, self.script, self.region) {
-            self.language = new_li.0;
-            self.script = new_li.1;
-            self.region = new_li.2;
-            true
-        } else {
-            false
-        }
+    pub fn with_language<S: AsRef<[u8]>>(
+        mut self,
+        language: S,
+    ) -> Result<Self, LanguageIdentifierError> {
+        self.language = subtags::Language::from_bytes(language.as_ref())?;
+        Ok(self)
     }
 
-    /// Extends the `LanguageIdentifier` removing likely subtags based
-    /// on tables provided by CLDR.
+    /// Consumes the `LanguageIdentifier` and returns it with the script
+    /// subtag replaced.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_langid_impl::LanguageIdentifier;
     ///
-    /// let mut li: LanguageIdentifier = "en-Latn-US".parse()
+    /// let li: LanguageIdentifier = "sr-RS".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// assert_eq!(li.minimize(), true);
-    /// assert_eq!(li.to_string(), "en");
+    /// let li = li.with_script("Latn")
+    ///     .expect("Setting script failed.");
+    /// assert_eq!(li.to_string(), "sr-Latn-RS");
     /// ```
-    #[cfg(feature = "likelysubtags")]
-    pub fn minimize(&mut self) -> bool {
-        if let Some(new_li) = likelysubtags::minimize(self.language, self.script, self.region) {
-            self.language = new_li.0;
-            self.script = new_li.1;
-            self.region = new_li.2;
-            true
-        } else {
-            false
-        }
+    pub fn with_script<S: AsRef<[u8]>>(
+        mut self,
+        script: S,
+    ) -> Result<Self, LanguageIdentifierError> {
+        self.script = Some(subtags::Script::from_bytes(script.as_ref())?);
+        Ok(self)
     }
 
-    /// Returns character direction of the `LanguageIdentifier`.
+    /// Consumes the `LanguageIdentifier` and returns it with the region
+    /// subtag replaced.
     ///
     /// # Examples
     ///
     /// ```
-    /// use unic_langid_impl::{LanguageIdentifier, CharacterDirection};
+    /// use unic_langid_impl::LanguageIdentifier;
     ///
-    /// let li1: LanguageIdentifier = "es-AR".parse()
-    ///     .expect("Parsing failed.");
-    /// let li2: LanguageIdentifier = "fa".parse()
+    /// let li: LanguageIdentifier = "en-US".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// assert_eq!(li1.character_direction(), CharacterDirection::LTR);
-    /// assert_eq!(li2.character_direction(), CharacterDirection::RTL);
+    /// let li = li.with_region("GB")
+    ///     .expect("Setting region failed.");
+    /// assert_eq!(li.to_string(), "en-GB");
     /// ```
-    pub fn character_direction(&self) -> CharacterDirection {
-        match (self.language.into(), self.script) {
-            (_, Some(script))
-                if layout_table::SCRIPTS_CHARACTER_DIRECTION_RTL.contains(&script.into()) =>
-            {
-                CharacterDirection::RTL
-            }
-            (Some(lang), _) if layout_table::LANGS_CHARACTER_DIRECTION_RTL.contains(&lang) => {
-                CharacterDirection::RTL
-            }
-            _ => CharacterDirection::LTR,
-        }
+    pub fn with_region<S: AsRef<[u8]>>(
+        mut self,
+        region: S,
+    ) -> Result<Self, LanguageIdentifierError> {
+        self.set_region(region)?;
+        Ok(self)
     }
-}
-
-impl FromStr for LanguageIdentifier {
-    type Err = LanguageIdentifierError;
 
-    fn from_str(source: &str) -> Result<Self, Self::Err> {
-        Self::from_bytes(source.as_bytes())
+    /// Consumes the `LanguageIdentifier` and returns it with the variant
+    /// subtags replaced.
+    ///
+    /// Duplicate variants result in an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "ca-ES".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let li = li.with_variants(&["valencia"])
+    ///     .expect("Setting variants failed.");
+    /// assert_eq!(li.to_string(), "ca-ES-valencia");
+    /// ```
+    pub fn with_variants<S: AsRef<[u8]>>(
+        mut self,
+        variants: &[S],
+    ) -> Result<Self, LanguageIdentifierError> {
+        let variants = variants
+            .iter()
+            .map(|v| subtags::Variant::from_bytes(v.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.set_unique_variants(variants)?;
+        Ok(self)
+    }
+
+    /// Returns a copy of the `LanguageIdentifier` without the script subtag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "sr-Cyrl-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.without_script().to_string(), "sr-RS");
+    /// ```
+    pub fn without_script(&self) -> Self {
+        Self {
+            script: None,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the `LanguageIdentifier` without the region subtag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "ca-ES-valencia".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.without_region().to_string(), "ca-valencia");
+    /// ```
+    pub fn without_region(&self) -> Self {
+        Self {
+            region: None,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the `LanguageIdentifier` without variant subtags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "ca-ES-valencia".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.without_variants().to_string(), "ca-ES");
+    /// ```
+    pub fn without_variants(&self) -> Self {
+        // Copies the remaining fields instead of `..self.clone()`, which would
+        // clone the variants only to drop them.
+        Self {
+            variants: None,
+            language: self.language,
+            script: self.script,
+            region: self.region,
+        }
+    }
+
+    /// Returns the language subtag as a string, or `default` if the language
+    /// is undefined (`und`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "fr-CA".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.language_or("en"), "fr");
+    ///
+    /// let li2: LanguageIdentifier = "und-CA".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li2.language_or("en"), "en");
+    /// ```
+    pub fn language_or<'a>(&'a self, default: &'a str) -> &'a str {
+        if self.language.is_empty() {
+            default
+        } else {
+            self.language.as_str()
+        }
+    }
+
+    /// Returns an iterator over variant subtags of the `LanguageIdentifier`.
+    ///
+    /// Variants are yielded in their canonical, sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "ca-ES-valencia".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.variants().map(|v| v.as_str()).collect::<Vec<_>>(), &["valencia"]);
+    ///
+    /// let li2: LanguageIdentifier = "en-scouse-fonipa".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li2.variants().map(Into::into).collect::<Vec<&str>>(), &["fonipa", "scouse"]);
+    ///
+    /// let li3: LanguageIdentifier = "de".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li3.variants().len(), 0);
+    /// ```
+    pub fn variants(&self) -> impl ExactSizeIterator<Item = &subtags::Variant> {
+        let variants: &[_] = match self.variants {
+            Some(ref v) => v,
+            None => &[],
+        };
+
+        variants.iter()
+    }
+
+    /// Sets variant subtags of the `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "ca-ES".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// li.set_variants(&["valencia".parse().expect("Parsing failed.")]);
+    ///
+    /// assert_eq!(li.to_string(), "ca-ES-valencia");
+    /// ```
+    pub fn set_variants(&mut self, variants: &[subtags::Variant]) {
+        let mut v = variants.to_vec();
+
+        if v.is_empty() {
+            self.variants = None;
+        } else {
+            v.sort_unstable();
+            v.dedup();
+            self.variants = Some(v.into_boxed_slice());
+        }
+    }
+
+    /// Parses and sets variant subtags of the `LanguageIdentifier`.
+    ///
+    /// Variants are stored in their canonical, sorted order. If any variant
+    /// fails to parse, or the same variant is passed more than once, an error
+    /// is returned and the existing variants are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "ca-ES".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// li.try_set_variants(&["valencia"])
+    ///     .expect("Setting variants failed.");
+    ///
+    /// assert_eq!(li.to_string(), "ca-ES-valencia");
+    ///
+    /// assert!(li.try_set_variants(&["macos", "macos"]).is_err());
+    /// assert!(li.try_set_variants(&["mac"]).is_err());
+    ///
+    /// assert_eq!(li.to_string(), "ca-ES-valencia");
+    /// ```
+    pub fn try_set_variants(&mut self, variants: &[&str]) -> Result<(), LanguageIdentifierError> {
+        let variants = variants
+            .iter()
+            .map(|v| subtags::Variant::from_bytes(v.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.set_unique_variants(variants)
+    }
+
+    pub(crate) fn set_unique_variants(
+        &mut self,
+        mut variants: Vec<subtags::Variant>,
+    ) -> Result<(), LanguageIdentifierError> {
+        variants.sort_unstable();
+        if let Some(w) = variants.windows(2).find(|w| w[0] == w[1]) {
+            return Err(parser::ParserError::DuplicateVariant(w[0]).into());
+        }
+        self.variants = if variants.is_empty() {
+            None
+        } else {
+            Some(variants.into_boxed_slice())
+        };
+        Ok(())
+    }
+
+    /// Tests if a variant subtag is present in the `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "ca-ES-macos".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.has_variant("valencia".parse().unwrap()), false);
+    /// assert_eq!(li.has_variant("macos".parse().unwrap()), true);
+    /// ```
+    pub fn has_variant(&self, variant: subtags::Variant) -> bool {
+        if let Some(variants) = &self.variants {
+            variants.contains(&variant)
+        } else {
+            false
+        }
+    }
+
+    /// Tests if a variant subtag, given as a string, is present in the
+    /// `LanguageIdentifier`.
+    ///
+    /// The argument is compared case-insensitively, and a malformed variant
+    /// is never present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "ca-ES-macos".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.has_variant_str("MacOS"), true);
+    /// assert_eq!(li.has_variant_str("valencia"), false);
+    /// assert_eq!(li.has_variant_str("-"), false);
+    /// ```
+    pub fn has_variant_str(&self, variant: &str) -> bool {
+        subtags::Variant::from_bytes(variant.as_bytes())
+            .map(|variant| self.has_variant(variant))
+            .unwrap_or(false)
+    }
+
+    /// Adds a variant subtag to the `LanguageIdentifier`, keeping the
+    /// variants in their canonical order.
+    ///
+    /// Returns `false` without modifying the `LanguageIdentifier` if the
+    /// variant is already present, and an error if it is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "sl-rozaj".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.add_variant("biske"), Ok(true));
+    /// assert_eq!(li.add_variant("ROZAJ"), Ok(false));
+    /// assert_eq!(li.to_string(), "sl-biske-rozaj");
+    /// ```
+    pub fn add_variant(&mut self, variant: &str) -> Result<bool, LanguageIdentifierError> {
+        let variant = subtags::Variant::from_bytes(variant.as_bytes())?;
+        let mut variants = self.variants.take().map(Vec::from).unwrap_or_default();
+        let inserted = match variants.binary_search(&variant) {
+            Ok(_) => false,
+            Err(idx) => {
+                variants.insert(idx, variant);
+                true
+            }
+        };
+        self.variants = Some(variants.into_boxed_slice());
+        Ok(inserted)
+    }
+
+    /// Removes a variant subtag from the `LanguageIdentifier`, matching it
+    /// case-insensitively.
+    ///
+    /// Returns `true` if the variant was present. The remaining variants keep
+    /// their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "sl-biske-rozaj".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.remove_variant("BISKE"), true);
+    /// assert_eq!(li.remove_variant("biske"), false);
+    /// assert_eq!(li.to_string(), "sl-rozaj");
+    /// ```
+    pub fn remove_variant(&mut self, variant: &str) -> bool {
+        let variant = match subtags::Variant::from_bytes(variant.as_bytes()) {
+            Ok(variant) => variant,
+            Err(_) => return false,
+        };
+        let mut variants = match self.variants.take() {
+            Some(variants) => Vec::from(variants),
+            None => return false,
+        };
+        let removed = match variants.binary_search(&variant) {
+            Ok(idx) => {
+                variants.remove(idx);
+                true
+            }
+            Err(_) => false,
+        };
+        if !variants.is_empty() {
+            self.variants = Some(variants.into_boxed_slice());
+        }
+        removed
+    }
+
+    /// Returns `true` if the language subtag of the `LanguageIdentifier` is
+    /// `lang`, compared case-insensitively and without allocating.
+    ///
+    /// Only whole subtags match, so `en` does not match `eng`. An undefined
+    /// language matches `und`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.starts_with_language("EN"), true);
+    /// assert_eq!(li.starts_with_language("eng"), false);
+    /// assert_eq!(li.starts_with_language("en-US"), false);
+    /// ```
+    pub fn starts_with_language(&self, lang: &str) -> bool {
+        self.language.as_str().eq_ignore_ascii_case(lang)
+    }
+
+    /// Returns `true` if the language subtag of the `LanguageIdentifier`
+    /// matches any of `langs`, compared case-insensitively.
+    ///
+    /// The candidates are tested in order, stopping at the first match.
+    /// Returns `false` for an empty slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "de-AT".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.language_matches_any(&["en", "DE", "fr"]), true);
+    /// assert_eq!(li.language_matches_any(&["en", "deu"]), false);
+    /// assert_eq!(li.language_matches_any(&[]), false);
+    /// ```
+    pub fn language_matches_any(&self, langs: &[&str]) -> bool {
+        langs.iter().any(|lang| self.starts_with_language(lang))
+    }
+
+    /// Returns `true` if the region subtag of the `LanguageIdentifier` is a
+    /// three digit UN M.49 code, such as `419`.
+    ///
+    /// Returns `false` for two letter ISO 3166-1 codes and when there is no
+    /// region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "es-419".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "es-MX".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.region_is_numeric(), true);
+    /// assert_eq!(li2.region_is_numeric(), false);
+    /// ```
+    pub fn region_is_numeric(&self) -> bool {
+        self.region
+            .as_ref()
+            .is_some_and(|r| r.as_str().as_bytes()[0].is_ascii_digit())
+    }
+
+    /// Returns the number of variant subtags of the `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "de-DE-1996-macos".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.variant_count(), 2);
+    /// ```
+    pub fn variant_count(&self) -> usize {
+        self.variants.as_ref().map_or(0, |v| v.len())
+    }
+
+    /// Returns a key for sorting lists of `LanguageIdentifier`s with
+    /// `sort_by`.
+    ///
+    /// The key borrows the variants of the `LanguageIdentifier`, so it cannot
+    /// be returned from the closure passed to `sort_by_key`.
+    ///
+    /// Unlike the `Ord` implementation, which matches string order, the key
+    /// compares subtags field by field: language, then script, then region,
+    /// then variants. A missing subtag sorts before any present one, so
+    /// `en-US` sorts before `en-Latn`.
+    ///
+    /// Regions compare by their canonical form. UN M.49 codes are always three
+    /// digits, so they compare numerically among themselves, and sort before
+    /// all alphabetic regions: `es-419` sorts before `es-AR`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut list: Vec<LanguageIdentifier> = vec![
+    ///     "es-AR".parse().expect("Parsing failed."),
+    ///     "en-Latn".parse().expect("Parsing failed."),
+    ///     "es-419".parse().expect("Parsing failed."),
+    ///     "en-US".parse().expect("Parsing failed."),
+    /// ];
+    /// list.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    ///
+    /// assert_eq!(list, vec!["en-US", "en-Latn", "es-419", "es-AR"]);
+    /// ```
+    pub fn sort_key(&self) -> impl Ord + '_ {
+        (
+            self.language,
+            self.script,
+            self.region,
+            self.variants.as_deref(),
+        )
+    }
+
+    /// Compares two `LanguageIdentifier`s by the packed integer representation
+    /// of their subtags, in a fixed order: language, script, region, then
+    /// variants in their canonical order.
+    ///
+    /// Each subtag is compared as the integer returned by `Language::as_u64`,
+    /// `Script::as_u32`, `Region::as_u32` or `Variant::as_u64`, with a missing
+    /// subtag (or `und`) sorting first. Since those integers are stable across
+    /// releases and platforms, the ordering is deterministic and independent
+    /// of any string collation. It is consistent with `Eq`, but differs from
+    /// the string order used by `Ord`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use std::cmp::Ordering;
+    ///
+    /// let li1: LanguageIdentifier = "ab".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "ba".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// // The packed integers are little-endian, so the last byte weighs most.
+    /// assert_eq!(li1.total_cmp(&li2), Ordering::Greater);
+    /// assert_eq!(li1.cmp(&li2), Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        fn key(langid: &LanguageIdentifier) -> (Option<u64>, Option<u32>, Option<u32>) {
+            (
+                langid.language.as_u64(),
+                langid.script.map(subtags::Script::as_u32),
+                langid.region.map(subtags::Region::as_u32),
+            )
+        }
+
+        key(self).cmp(&key(other)).then_with(|| {
+            self.variants()
+                .map(|v| v.as_u64())
+                .cmp(other.variants().map(|v| v.as_u64()))
+        })
+    }
+
+    /// Clears variant subtags of the `LanguageIdentifier`.
+    ///
+    /// Calling this method on a `LanguageIdentifier` without variants is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "ca-ES-valencia".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// li.clear_variants();
+    ///
+    /// assert_eq!(li.to_string(), "ca-ES");
+    /// ```
+    pub fn clear_variants(&mut self) {
+        self.variants = None;
+    }
+
+    /// Resets the `LanguageIdentifier` to `und`, clearing the language
+    /// and removing the script, region and variant subtags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "ca-Latn-ES-valencia".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// li.clear();
+    ///
+    /// assert_eq!(li.to_string(), "und");
+    /// assert_eq!(li.is_empty(), true);
+    /// ```
+    pub fn clear(&mut self) {
+        self.language.clear();
+        self.script = None;
+        self.region = None;
+        self.clear_variants();
+    }
+
+    /// Returns an iterator over progressively less specific identifiers,
+    /// starting with a clone of the `LanguageIdentifier` itself.
+    ///
+    /// Each step drops the last subtag: first the variants, then the region,
+    /// then the script. The iterator ends at the language-only form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "ca-Latn-ES-valencia".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let chain: Vec<String> = li.fallback_chain()
+    ///     .map(|li| li.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(chain, &["ca-Latn-ES-valencia", "ca-Latn-ES", "ca-Latn", "ca"]);
+    /// ```
+    pub fn fallback_chain(&self) -> impl Iterator<Item = LanguageIdentifier> {
+        core::iter::successors(Some(self.clone()), |prev| {
+            let mut next = prev.clone();
+            if next.variants.is_some() {
+                next.clear_variants();
+            } else if next.region.is_some() {
+                next.region = None;
+            } else if next.script.is_some() {
+                next.script = None;
+            } else {
+                return None;
+            }
+            Some(next)
+        })
+    }
+
+    /// Extends the `LanguageIdentifier` adding likely subtags based
+    /// on tables provided by CLDR.
+    ///
+    /// The CLDR likely subtags data only covers the language, script and
+    /// region, so variants are never added. Existing variants are always
+    /// preserved, both here and in `minimize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "de-1996".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.maximize(), true);
+    /// assert_eq!(li.to_string(), "de-Latn-DE-1996");
+    ///
+    /// let mut li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.maximize(), true);
+    /// assert_eq!(li.to_string(), "en-Latn-US");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn maximize(&mut self) -> bool {
+        if let Some(new_li) = likelysubtags::maximize(self.language, self.script, self.region) {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the script of the `LanguageIdentifier`, or its likely script
+    /// based on tables provided by CLDR if the script is not specified.
+    ///
+    /// The identifier is not modified. Returns `None` only when there is no
+    /// likely subtags data for the identifier.
+    ///
+    /// The script is returned by value since a likely script is not stored in
+    /// the identifier; use `Script::as_str` to get a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "sr".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.script_or_likely().unwrap(), "Cyrl");
+    /// assert_eq!(li.script, None);
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn script_or_likely(&self) -> Option<subtags::Script> {
+        self.script.or_else(|| {
+            likelysubtags::maximize(self.language, self.script, self.region)
+                .and_then(|(_, script, _)| script)
+        })
+    }
+
+    /// Extends the `LanguageIdentifier` adding likely subtags based
+    /// on tables provided by CLDR.
+    ///
+    /// Unlike `maximize`, this method distinguishes between an identifier
+    /// that is already maximal, for which it returns `Ok(false)`, and one for
+    /// which no likely subtags data is available, for which it returns an error.
+    /// An identifier with all of language, script and region is left
+    /// unchanged, but still reports an error when the data knows nothing
+    /// about its subtags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, LikelySubtagsError};
+    ///
+    /// let mut li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.try_maximize(), Ok(true));
+    /// assert_eq!(li.to_string(), "en-Latn-US");
+    ///
+    /// assert_eq!(li.try_maximize(), Ok(false));
+    ///
+    /// let mut li: LanguageIdentifier = "zxx".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.try_maximize(), Err(LikelySubtagsError::NoData));
+    /// assert_eq!(li.to_string(), "zxx");
+    ///
+    /// let mut li: LanguageIdentifier = "zxx-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.try_maximize(), Err(LikelySubtagsError::NoData));
+    /// assert_eq!(li.to_string(), "zxx-Latn-US");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn try_maximize(&mut self) -> Result<bool, LikelySubtagsError> {
+        if !self.language.is_empty() && self.script.is_some() && self.region.is_some() {
+            // Nothing to add, but the subtags are still looked up so that
+            // `zxx-Latn-US` reports missing data just like `zxx-US`.
+            return likelysubtags::maximize(self.language, self.script, None)
+                .or_else(|| likelysubtags::maximize(self.language, None, self.region))
+                .map(|_| false)
+                .ok_or(LikelySubtagsError::NoData);
+        }
+
+        let new_li = likelysubtags::maximize(self.language, self.script, self.region)
+            .ok_or(LikelySubtagsError::NoData)?;
+        let changed = new_li != (self.language, self.script, self.region);
+        self.language = new_li.0;
+        self.script = new_li.1;
+        self.region = new_li.2;
+        Ok(changed)
+    }
+
+    /// Extends the `LanguageIdentifier` removing likely subtags based
+    /// on tables provided by CLDR.
+    ///
+    /// Variants are preserved and don't take part in the minimization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "en-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.minimize(), true);
+    /// assert_eq!(li.to_string(), "en");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize(&mut self) -> bool {
+        if let Some(new_li) = likelysubtags::minimize(self.language, self.script, self.region) {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Extends the `LanguageIdentifier` adding likely subtags based on
+    /// the given likely subtags data instead of the embedded CLDR tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::likelysubtags::LikelySubtagsData;
+    ///
+    /// let mut li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.maximize_with(LikelySubtagsData::cldr()), true);
+    /// assert_eq!(li.to_string(), "en-Latn-US");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn maximize_with(&mut self, data: &likelysubtags::LikelySubtagsData) -> bool {
+        if let Some(new_li) =
+            likelysubtags::maximize_with(data, self.language, self.script, self.region)
+        {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes likely subtags from the `LanguageIdentifier` based on
+    /// the given likely subtags data instead of the embedded CLDR tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::likelysubtags::LikelySubtagsData;
+    ///
+    /// let mut li: LanguageIdentifier = "en-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.minimize_with(LikelySubtagsData::cldr()), true);
+    /// assert_eq!(li.to_string(), "en");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize_with(&mut self, data: &likelysubtags::LikelySubtagsData) -> bool {
+        if let Some(new_li) =
+            likelysubtags::minimize_with(data, self.language, self.script, self.region)
+        {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns a maximized copy of the `LanguageIdentifier`, leaving
+    /// the original unchanged.
+    ///
+    /// See `maximize` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.maximized().to_string(), "en-Latn-US");
+    /// assert_eq!(li.to_string(), "en-US");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn maximized(&self) -> Self {
+        let mut result = self.clone();
+        result.maximize();
+        result
+    }
+
+    /// Returns a minimized copy of the `LanguageIdentifier`, leaving
+    /// the original unchanged.
+    ///
+    /// See `minimize` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.minimized().to_string(), "en");
+    /// assert_eq!(li.to_string(), "en-Latn-US");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimized(&self) -> Self {
+        let mut result = self.clone();
+        result.minimize();
+        result
+    }
+
+    /// Extends the `LanguageIdentifier` removing likely subtags based
+    /// on tables provided by CLDR, while always keeping the script subtag.
+    ///
+    /// This is useful when the script carries meaning for display purposes
+    /// even if it is the likely script for the language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "sr-Cyrl-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.minimize_keeping_script(), true);
+    /// assert_eq!(li.to_string(), "sr-Cyrl");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize_keeping_script(&mut self) -> bool {
+        if let Some(new_li) =
+            likelysubtags::minimize_keeping_script(self.language, self.script, self.region)
+        {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replaces deprecated language, script and region subtags with their
+    /// modern equivalents, based on the CLDR alias data.
+    ///
+    /// A language alias may also provide script or region subtags, which
+    /// are only used if the `LanguageIdentifier` does not have them already.
+    ///
+    /// A region which was split into several successors, such as `CS` or
+    /// `SU`, is replaced with the successor in which the language is most
+    /// likely spoken if the `likelysubtags` feature is enabled. Otherwise, or
+    /// if the likely region is not a successor, the primary successor listed
+    /// first by CLDR is used, such as `RS` for `CS` and `RU` for `SU`.
+    ///
+    /// Returns `true` if any subtag has been replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "iw-IL".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.canonicalize_aliases(), true);
+    /// assert_eq!(li.to_string(), "he-IL");
+    ///
+    /// let mut li: LanguageIdentifier = "sh-YU".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.canonicalize_aliases(), true);
+    /// assert_eq!(li.to_string(), "sr-Latn-RS");
+    /// ```
+    pub fn canonicalize_aliases(&mut self) -> bool {
+        let mut modified = false;
+
+        if let Some(replacement) = aliases::language(self.language) {
+            self.language = replacement.language;
+            if self.script.is_none() {
+                self.script = replacement.script;
+            }
+            if self.region.is_none() {
+                self.region = replacement.region;
+            }
+            modified = true;
+        }
+
+        if let Some(script) = self.script.and_then(aliases::script) {
+            self.script = Some(script);
+            modified = true;
+        }
+
+        if let Some(region) = self
+            .region
+            .and_then(|region| aliases::region(region, self.language, self.script))
+        {
+            self.region = Some(region);
+            modified = true;
+        }
+
+        modified
+    }
+
+    /// Returns character direction of the `LanguageIdentifier`.
+    ///
+    /// If the script subtag is present, it alone decides the direction.
+    /// Otherwise the direction is based on the language subtag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, CharacterDirection};
+    ///
+    /// let li1: LanguageIdentifier = "es-AR".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "fa".parse()
+    ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "ff-Adlm".parse()
+    ///     .expect("Parsing failed.");
+    /// let li4: LanguageIdentifier = "ar-Latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.character_direction(), CharacterDirection::LTR);
+    /// assert_eq!(li2.character_direction(), CharacterDirection::RTL);
+    /// assert_eq!(li3.character_direction(), CharacterDirection::RTL);
+    /// assert_eq!(li4.character_direction(), CharacterDirection::LTR);
+    /// ```
+    #[cfg(feature = "direction")]
+    pub fn character_direction(&self) -> CharacterDirection {
+        match (self.language.into(), self.script) {
+            (_, Some(script))
+                if layout_table::SCRIPTS_CHARACTER_DIRECTION_RTL.contains(&script.into()) =>
+            {
+                CharacterDirection::RTL
+            }
+            (_, Some(_)) => CharacterDirection::LTR,
+            (Some(lang), None) if layout_table::LANGS_CHARACTER_DIRECTION_RTL.contains(&lang) => {
+                CharacterDirection::RTL
+            }
+            _ => CharacterDirection::LTR,
+        }
+    }
+
+    /// Serializes the `LanguageIdentifier` into its canonical form, using
+    /// the given `separator` between subtags instead of `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-Latn-US-posix".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_string_with_separator('_'), "en_Latn_US_posix");
+    /// assert_eq!(li.to_string_with_separator('-'), li.to_string());
+    /// ```
+    pub fn to_string_with_separator(&self, separator: char) -> String {
+        let mut result = String::new();
+        for (idx, subtag) in self.subtags().enumerate() {
+            if idx != 0 {
+                result.push(separator);
+            }
+            result.push_str(subtag);
+        }
+        result
+    }
+
+    /// Serializes the `LanguageIdentifier` into an ICU locale ID, matching
+    /// the output of ICU's `uloc_getName` for the subtags.
+    ///
+    /// Subtags are separated by `_`. The language, script and region keep
+    /// their canonical casing, while variants are uppercased as in ICU.
+    /// An `und` language is left empty, and a missing region is left empty
+    /// when followed by variants, so `und-Latn` becomes `_Latn` and
+    /// `sl-rozaj` becomes `sl__ROZAJ`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-Latn-US-posix".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_icu_locale_id(), "en_Latn_US_POSIX");
+    /// ```
+    pub fn to_icu_locale_id(&self) -> String {
+        let mut result = String::new();
+        if !self.language.is_empty() {
+            result.push_str(self.language.as_str());
+        }
+        if let Some(ref script) = self.script {
+            result.push('_');
+            result.push_str(script.as_str());
+        }
+        if let Some(ref region) = self.region {
+            result.push('_');
+            result.push_str(region.as_str());
+        } else if self.variant_count() > 0 {
+            result.push('_');
+        }
+        for variant in self.variants() {
+            result.push('_');
+            result.push_str(&variant.as_str().to_ascii_uppercase());
+        }
+        result
+    }
+}
+
+impl FromStr for LanguageIdentifier {
+    type Err = LanguageIdentifierError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(source.as_bytes())
+    }
+}
+
+impl TryFrom<&str> for LanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    fn try_from(source: &str) -> Result<Self, Self::Error> {
+        source.parse()
+    }
+}
+
+impl TryFrom<String> for LanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        source.parse()
     }
 }
 
@@ -441,30 +1845,205 @@ impl AsRef<LanguageIdentifier> for LanguageIdentifier {
     }
 }
 
-impl std::fmt::Display for LanguageIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.language.fmt(f)?;
+impl LanguageIdentifier {
+    fn subtags(&self) -> impl Iterator<Item = &str> {
+        core::iter::once(self.language.as_str())
+            .chain(self.script.as_ref().map(|s| s.as_str()))
+            .chain(self.region.as_ref().map(|r| r.as_str()))
+            .chain(self.variants().map(|v| v.as_str()))
+    }
+}
+
+impl Clone for LanguageIdentifier {
+    fn clone(&self) -> Self {
+        Self {
+            language: self.language,
+            script: self.script,
+            region: self.region,
+            variants: self.variants.clone(),
+        }
+    }
+
+    /// Overwrites `self` with a copy of `source`, which `ToOwned::clone_into`
+    /// also relies on.
+    ///
+    /// The variants are stored in a boxed slice, so their allocation is only
+    /// reused when `self` holds exactly as many variants as `source`.
+    fn clone_from(&mut self, source: &Self) {
+        self.language = source.language;
+        self.script = source.script;
+        self.region = source.region;
+        match (&mut self.variants, &source.variants) {
+            (Some(target), Some(source)) if target.len() == source.len() => {
+                target.copy_from_slice(source);
+            }
+            (target, source) => *target = source.clone(),
+        }
+    }
+}
+
+impl PartialOrd for LanguageIdentifier {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LanguageIdentifier {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.subtags().cmp(other.subtags())
+    }
+}
+
+/// The size of the stack buffer used to serialize a `LanguageIdentifier`
+/// with a single write. It fits the language, script, region and up to
+/// five variants of maximum length.
+const DISPLAY_BUFFER_SIZE: usize = 64;
+
+/// Serializes the `LanguageIdentifier` into its canonical BCP 47 form, with
+/// subtags separated by `-`.
+///
+/// The alternate form (`{:#}`) separates subtags with `_` instead, keeping the
+/// canonical casing of each subtag: a lowercase language, a titlecase script,
+/// an uppercase region and lowercase variants, such as `en_Latn_US_posix`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+///
+/// let li: LanguageIdentifier = "EN-us".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(format!("{}", li), "en-US");
+/// assert_eq!(format!("{:#}", li), "en_US");
+/// ```
+impl core::fmt::Display for LanguageIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let separator = if f.alternate() { b'_' } else { b'-' };
+
+        // Assemble the identifier on the stack so that the formatter sees
+        // a single write, which lets `to_string` allocate exactly once.
+        let mut buf = DisplayBuffer::new(separator);
+        buf.push(self.language.as_str());
         if let Some(ref script) = self.script {
-            f.write_char('-')?;
-            script.fmt(f)?;
+            buf.push(script.as_str());
         }
         if let Some(ref region) = self.region {
-            f.write_char('-')?;
-            region.fmt(f)?;
+            buf.push(region.as_str());
         }
-        if let Some(variants) = &self.variants {
-            for variant in variants.iter() {
-                f.write_char('-')?;
-                variant.fmt(f)?;
+        for variant in self.variants() {
+            buf.push(variant.as_str());
+        }
+
+        match buf.as_str() {
+            Some(s) => f.write_str(s),
+            None => {
+                // Fall back on writing subtag by subtag.
+                for (idx, subtag) in self.subtags().enumerate() {
+                    if idx != 0 {
+                        f.write_char(char::from(separator))?;
+                    }
+                    f.write_str(subtag)?;
+                }
+                Ok(())
             }
         }
-        Ok(())
+    }
+}
+
+/// A stack buffer joining subtags with a separator, used by `Display`.
+struct DisplayBuffer {
+    buf: [u8; DISPLAY_BUFFER_SIZE],
+    // `None` once the subtags no longer fit.
+    len: Option<usize>,
+    separator: u8,
+}
+
+impl DisplayBuffer {
+    fn new(separator: u8) -> Self {
+        Self {
+            buf: [0; DISPLAY_BUFFER_SIZE],
+            len: Some(0),
+            separator,
+        }
+    }
+
+    fn push(&mut self, subtag: &str) {
+        if let Some(len) = self.len {
+            let start = if len == 0 { 0 } else { len + 1 };
+            let end = start + subtag.len();
+            if end > DISPLAY_BUFFER_SIZE {
+                self.len = None;
+                return;
+            }
+            if len != 0 {
+                self.buf[len] = self.separator;
+            }
+            self.buf[start..end].copy_from_slice(subtag.as_bytes());
+            self.len = Some(end);
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        // `None` when the subtags did not fit, or if the bytes are not valid
+        // UTF-8, in which case `Display` writes subtag by subtag instead.
+        self.len
+            .and_then(|len| core::str::from_utf8(&self.buf[..len]).ok())
+    }
+}
+
+/// Compares a `LanguageIdentifier` to a string subtag by subtag, without
+/// allocating.
+///
+/// Casing and separators in the string are not significant, but its subtags
+/// must be in canonical order, including variants, which are sorted
+/// alphabetically. A string which is malformed or not in canonical order
+/// compares as not equal.
+///
+/// As when parsing, a grandfathered tag is compared by its preferred value.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+///
+/// let li: LanguageIdentifier = "en-US".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li, "en-US");
+/// assert_eq!(li, "EN_us");
+/// assert_ne!(li, "en");
+///
+/// let li: LanguageIdentifier = "en-US-posix-macos".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li, "en-US-macos-posix");
+/// assert_ne!(li, "en-US-posix-macos");
+///
+/// let li: LanguageIdentifier = "art-lojban".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li, "art-lojban");
+/// assert_eq!(li, "jbo");
+/// ```
+impl PartialEq<str> for LanguageIdentifier {
+    fn eq(&self, other: &str) -> bool {
+        if let Some(preferred) = aliases::grandfathered(other.as_bytes()) {
+            return self == preferred;
+        }
+
+        let mut others = other.split(['-', '_']);
+        self.subtags().all(|subtag| {
+            others
+                .next()
+                .is_some_and(|o| o.eq_ignore_ascii_case(subtag))
+        }) && others.next().is_none()
     }
 }
 
 impl PartialEq<&str> for LanguageIdentifier {
     fn eq(&self, other: &&str) -> bool {
-        self.to_string().as_str() == *other
+        self == *other
     }
 }
 
@@ -477,8 +2056,14 @@ fn subtag_matches<P: PartialEq>(
     (as_range1 && subtag1.is_none()) || (as_range2 && subtag2.is_none()) || subtag1 == subtag2
 }
 
+/// Strips the zero padding from the little-endian bytes of a raw subtag.
+fn raw_bytes(bytes: &[u8]) -> &[u8] {
+    let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |idx| idx + 1);
+    &bytes[..len]
+}
+
 fn is_option_empty<P: PartialEq>(subtag: &Option<Box<[P]>>) -> bool {
-    subtag.as_ref().map_or(true, |t| t.is_empty())
+    subtag.as_ref().is_none_or(|t| t.is_empty())
 }
 
 fn subtags_match<P: PartialEq>(
@@ -495,8 +2080,11 @@ fn subtags_match<P: PartialEq>(
 
 /// This is a best-effort operation that performs all available levels of canonicalization.
 ///
-/// At the moment the operation will normalize casing and the separator, but in the future
-/// it may also validate and update from deprecated subtags to canonical ones.
+/// At the moment the operation will normalize casing, the separator and the order of
+/// variants, but in the future it may also validate and update from deprecated subtags
+/// to canonical ones.
+///
+/// Malformed input results in an error.
 ///
 /// # Examples
 ///
@@ -504,12 +2092,174 @@ fn subtags_match<P: PartialEq>(
 /// use unic_langid_impl::canonicalize;
 ///
 /// assert_eq!(canonicalize("pL_latn_pl"), Ok("pl-Latn-PL".to_string()));
+/// assert_eq!(canonicalize("EN_us_POSIX"), Ok("en-US-posix".to_string()));
+/// assert!(canonicalize("en-US-").is_err());
 /// ```
 pub fn canonicalize<S: AsRef<[u8]>>(input: S) -> Result<String, LanguageIdentifierError> {
     let lang_id = LanguageIdentifier::from_bytes(input.as_ref())?;
     Ok(lang_id.to_string())
 }
 
+/// Canonicalizes `input` like `canonicalize`, but borrows the input instead
+/// of allocating when it is already canonical.
+///
+/// Checking whether the input is canonical compares the parsed subtags with
+/// the input and doesn't allocate.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use unic_langid_impl::canonicalize_cow;
+///
+/// assert_eq!(canonicalize_cow("en-US"), Ok(Cow::Borrowed("en-US")));
+/// assert_eq!(canonicalize_cow("en_us"), Ok(Cow::Owned("en-US".to_string())));
+/// assert!(canonicalize_cow("en-US-").is_err());
+/// ```
+pub fn canonicalize_cow(input: &str) -> Result<Cow<'_, str>, LanguageIdentifierError> {
+    let lang_id = LanguageIdentifier::from_bytes(input.as_bytes())?;
+    if lang_id.subtags().eq(input.split('-')) {
+        Ok(Cow::Borrowed(input))
+    } else {
+        Ok(Cow::Owned(lang_id.to_string()))
+    }
+}
+
+/// Canonicalizes `s` in place, like `canonicalize`, without constructing a
+/// new `String`.
+///
+/// The existing allocation is reused whenever it is large enough, which is
+/// always the case unless the input is a grandfathered tag with a longer
+/// replacement. If the input is malformed, an error is returned and `s` is
+/// left unmodified.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::normalize_in_place;
+///
+/// let mut s = String::from("EN_us_POSIX_1996");
+/// normalize_in_place(&mut s).expect("Normalizing failed.");
+/// assert_eq!(s, "en-US-1996-posix");
+///
+/// let mut s = String::from("en-US-");
+/// assert!(normalize_in_place(&mut s).is_err());
+/// assert_eq!(s, "en-US-");
+/// ```
+pub fn normalize_in_place(s: &mut String) -> Result<(), LanguageIdentifierError> {
+    let lang_id = LanguageIdentifier::from_bytes(s.as_bytes())?;
+    s.clear();
+    for (idx, subtag) in lang_id.subtags().enumerate() {
+        if idx > 0 {
+            s.push('-');
+        }
+        s.push_str(subtag);
+    }
+    Ok(())
+}
+
+/// Compares two raw tag strings, ignoring ASCII casing and treating `-` and `_`
+/// as the same separator.
+///
+/// The input is not parsed or validated, which makes this a cheap, allocation-free
+/// pre-filter before full parsing.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::tags_equivalent;
+///
+/// assert!(tags_equivalent("EN_us", "en-US"));
+/// assert!(!tags_equivalent("en-US", "en-GB"));
+/// ```
+pub fn tags_equivalent(a: &str, b: &str) -> bool {
+    fn normalize(b: u8) -> u8 {
+        match b {
+            b'_' => b'-',
+            b => b.to_ascii_lowercase(),
+        }
+    }
+
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .all(|(a, b)| normalize(a) == normalize(b))
+}
+
+/// Returns the character direction of a raw language subtag, such as `ar`.
+///
+/// Only the primary language subtag is considered, so scripts which would
+/// change the direction (such as `ar-Latn`) are not taken into account.
+/// Malformed or unknown languages default to `CharacterDirection::LTR`.
+///
+/// This avoids constructing a `LanguageIdentifier` when only the language is known.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{character_direction_for_language, CharacterDirection};
+///
+/// assert_eq!(character_direction_for_language("he"), CharacterDirection::RTL);
+/// assert_eq!(character_direction_for_language("en"), CharacterDirection::LTR);
+/// assert_eq!(character_direction_for_language("??"), CharacterDirection::LTR);
+/// ```
+#[cfg(feature = "direction")]
+pub fn character_direction_for_language(lang: &str) -> CharacterDirection {
+    match subtags::Language::from_bytes(lang.as_bytes()).map(Into::into) {
+        Ok(Some(lang)) if layout_table::LANGS_CHARACTER_DIRECTION_RTL.contains(&lang) => {
+            CharacterDirection::RTL
+        }
+        _ => CharacterDirection::LTR,
+    }
+}
+
+/// Returns the languages written right-to-left by default, in alphabetical
+/// order.
+///
+/// These are the languages for which `character_direction_for_language`
+/// returns `CharacterDirection::RTL`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::rtl_languages;
+///
+/// assert!(rtl_languages().contains(&"ar"));
+/// assert!(!rtl_languages().contains(&"en"));
+/// ```
+#[cfg(feature = "direction")]
+pub fn rtl_languages() -> &'static [&'static str] {
+    &layout_table::LANGS_CHARACTER_DIRECTION_RTL_STR
+}
+
+/// Returns the character direction of a raw script subtag, such as `Arab`.
+///
+/// The right-to-left scripts are taken from CLDR `scriptMetadata`. The script
+/// is compared case-insensitively, and malformed or unknown scripts default
+/// to `CharacterDirection::LTR`.
+///
+/// Unlike `LanguageIdentifier::character_direction`, the language is not
+/// taken into account, which is useful for isolated runs of text.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{script_direction, CharacterDirection};
+///
+/// assert_eq!(script_direction("Hebr"), CharacterDirection::RTL);
+/// assert_eq!(script_direction("latn"), CharacterDirection::LTR);
+/// assert_eq!(script_direction("????"), CharacterDirection::LTR);
+/// ```
+#[cfg(feature = "direction")]
+pub fn script_direction(script: &str) -> CharacterDirection {
+    match subtags::Script::from_bytes(script.as_bytes()) {
+        Ok(script) if layout_table::SCRIPTS_CHARACTER_DIRECTION_RTL.contains(&script.into()) => {
+            CharacterDirection::RTL
+        }
+        _ => CharacterDirection::LTR,
+    }
+}
+
 #[test]
 fn invalid_subtag() {
     assert!(LanguageIdentifier::from_bytes("en-ÁÁÁÁ".as_bytes()).is_err());
@@ -2,10 +2,139 @@ mod tables;
 
 pub use tables::CLDR_VERSION;
 
+use crate::errors::LikelySubtagsError;
 use crate::subtags;
 
+const UND: u64 = 6_581_877; // "und"
+
+/// The likely language, script and region of an entry, using the packed
+/// integer representation of each subtag (see `Language::as_u64`,
+/// `Script::as_u32` and `Region::as_u32`).
+pub type LikelySubtagsEntry = (Option<u64>, Option<u32>, Option<u32>);
+
+/// A set of likely subtags tables, in the layout of the tables generated
+/// from CLDR `likelySubtags.json`.
+///
+/// The embedded CLDR data is available as `LikelySubtagsData::cldr()`. Custom
+/// data, for example from a newer CLDR release, can be validated with
+/// `LikelySubtagsData::new` and passed to the `*_with` functions.
+///
+/// The data only borrows the tables, which have to outlive it. Tables are
+/// usually `static`, but may also be owned by the application for as long
+/// as the data is in use.
+#[derive(Debug, Clone, Copy)]
+pub struct LikelySubtagsData<'a> {
+    lang_only: &'a [(u64, LikelySubtagsEntry)],
+    lang_region: &'a [(u64, u32, LikelySubtagsEntry)],
+    lang_script: &'a [(u64, u32, LikelySubtagsEntry)],
+    script_region: &'a [(u32, u32, LikelySubtagsEntry)],
+    script_only: &'a [(u32, LikelySubtagsEntry)],
+    region_only: &'a [(u32, LikelySubtagsEntry)],
+}
+
+static CLDR: LikelySubtagsData<'static> = LikelySubtagsData {
+    lang_only: &tables::LANG_ONLY,
+    lang_region: &tables::LANG_REGION,
+    lang_script: &tables::LANG_SCRIPT,
+    script_region: &tables::SCRIPT_REGION,
+    script_only: &tables::SCRIPT_ONLY,
+    region_only: &tables::REGION_ONLY,
+};
+
+impl<'a> LikelySubtagsData<'a> {
+    /// Returns the embedded likely subtags data, for `CLDR_VERSION`.
+    pub fn cldr() -> &'static LikelySubtagsData<'static> {
+        &CLDR
+    }
+
+    /// Validates and wraps a set of likely subtags tables.
+    ///
+    /// Each table has to be sorted by its keys, without duplicates, and
+    /// every entry has to hold canonical, well-formed subtags. Otherwise
+    /// `LikelySubtagsError::InvalidData` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::likelysubtags::LikelySubtagsData;
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let en: LanguageIdentifier = "en".parse()
+    ///     .expect("Parsing failed.");
+    /// let (lang, _, _, _) = en.into_raw_parts();
+    /// let lang = lang.expect("Language is set.");
+    /// let latn = u32::from_le_bytes(*b"Latn");
+    /// let gb = u32::from_le_bytes(*b"GB\0\0");
+    ///
+    /// let lang_only = [(lang, (Some(lang), Some(latn), Some(gb)))];
+    /// let data = LikelySubtagsData::new(&lang_only, &[], &[], &[], &[], &[])
+    ///     .expect("Invalid data.");
+    ///
+    /// let mut li: LanguageIdentifier = "en".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(li.maximize_with(&data), true);
+    /// assert_eq!(li.to_string(), "en-Latn-GB");
+    /// ```
+    pub fn new(
+        lang_only: &'a [(u64, LikelySubtagsEntry)],
+        lang_region: &'a [(u64, u32, LikelySubtagsEntry)],
+        lang_script: &'a [(u64, u32, LikelySubtagsEntry)],
+        script_region: &'a [(u32, u32, LikelySubtagsEntry)],
+        script_only: &'a [(u32, LikelySubtagsEntry)],
+        region_only: &'a [(u32, LikelySubtagsEntry)],
+    ) -> Result<Self, LikelySubtagsError> {
+        let valid = lang_only.windows(2).all(|w| w[0].0 < w[1].0)
+            && lang_region
+                .windows(2)
+                .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1))
+            && lang_script
+                .windows(2)
+                .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1))
+            && script_region
+                .windows(2)
+                .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1))
+            && script_only.windows(2).all(|w| w[0].0 < w[1].0)
+            && region_only.windows(2).all(|w| w[0].0 < w[1].0)
+            && lang_only.iter().all(|(_, e)| is_valid_entry(e))
+            && lang_region.iter().all(|(_, _, e)| is_valid_entry(e))
+            && lang_script.iter().all(|(_, _, e)| is_valid_entry(e))
+            && script_region.iter().all(|(_, _, e)| is_valid_entry(e))
+            && script_only.iter().all(|(_, e)| is_valid_entry(e))
+            && region_only.iter().all(|(_, e)| is_valid_entry(e));
+
+        if !valid {
+            return Err(LikelySubtagsError::InvalidData);
+        }
+
+        Ok(Self {
+            lang_only,
+            lang_region,
+            lang_script,
+            script_region,
+            script_only,
+            region_only,
+        })
+    }
+}
+
+/// Checks that every subtag of the entry is well-formed and canonical, so
+/// that it can be turned into subtags without validation.
+fn is_valid_entry(entry: &LikelySubtagsEntry) -> bool {
+    let (lang, script, region) = *entry;
+    lang.is_none_or(|l| {
+        subtags::Language::from_bytes(crate::raw_bytes(&l.to_le_bytes()))
+            .is_ok_and(|parsed| parsed.as_u64() == Some(l))
+    }) && script.is_none_or(|s| {
+        subtags::Script::from_bytes(crate::raw_bytes(&s.to_le_bytes()))
+            .is_ok_and(|parsed| parsed.as_u32() == s)
+    }) && region.is_none_or(|r| {
+        subtags::Region::from_bytes(crate::raw_bytes(&r.to_le_bytes()))
+            .is_ok_and(|parsed| parsed.as_u32() == r)
+    })
+}
+
 unsafe fn lang_from_parts(
-    input: (Option<u64>, Option<u32>, Option<u32>),
+    input: LikelySubtagsEntry,
     lang: Option<subtags::Language>,
     script: Option<subtags::Script>,
     region: Option<subtags::Region>,
@@ -30,6 +159,20 @@ pub fn maximize(
     subtags::Language,
     Option<subtags::Script>,
     Option<subtags::Region>,
+)> {
+    maximize_with(&CLDR, lang, script, region)
+}
+
+/// Like `maximize`, using the given likely subtags data.
+pub fn maximize_with(
+    data: &LikelySubtagsData,
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<(
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
 )> {
     if !lang.is_empty() && script.is_some() && region.is_some() {
         return None;
@@ -37,64 +180,84 @@ pub fn maximize(
 
     if let Some(l) = Into::<Option<u64>>::into(lang) {
         if let Some(r) = region {
-            let result = tables::LANG_REGION
-                .binary_search_by_key(&(&l.into(), &r.into()), |(key_l, key_r, _)| (key_l, key_r))
+            let result = data
+                .lang_region
+                .binary_search_by_key(&(&l, &r.into()), |(key_l, key_r, _)| (key_l, key_r))
                 .ok();
             if let Some(r) = result {
-                // safe because all table entries are well formed.
-                return unsafe { lang_from_parts(tables::LANG_REGION[r].2, None, None, None) };
+                // safe because all table entries are validated.
+                return unsafe { lang_from_parts(data.lang_region[r].2, None, None, None) };
             }
         }
 
         if let Some(s) = script {
-            let result = tables::LANG_SCRIPT
-                .binary_search_by_key(&(&l.into(), &s.into()), |(key_l, key_s, _)| (key_l, key_s))
+            let result = data
+                .lang_script
+                .binary_search_by_key(&(&l, &s.into()), |(key_l, key_s, _)| (key_l, key_s))
                 .ok();
             if let Some(r) = result {
-                // safe because all table entries are well formed.
-                return unsafe { lang_from_parts(tables::LANG_SCRIPT[r].2, None, None, None) };
+                // safe because all table entries are validated.
+                return unsafe { lang_from_parts(data.lang_script[r].2, None, None, None) };
             }
         }
 
-        let result = tables::LANG_ONLY
-            .binary_search_by_key(&(&l.into()), |(key_l, _)| key_l)
+        let result = data
+            .lang_only
+            .binary_search_by_key(&&l, |(key_l, _)| key_l)
             .ok();
         if let Some(r) = result {
-            // safe because all table entries are well formed.
-            return unsafe { lang_from_parts(tables::LANG_ONLY[r].1, None, script, region) };
+            // safe because all table entries are validated.
+            return unsafe { lang_from_parts(data.lang_only[r].1, None, script, region) };
         }
     } else if let Some(s) = script {
         if let Some(r) = region {
-            let result = tables::SCRIPT_REGION
+            let result = data
+                .script_region
                 .binary_search_by_key(&(&s.into(), &r.into()), |(key_s, key_r, _)| (key_s, key_r))
                 .ok();
             if let Some(r) = result {
-                // safe because all table entries are well formed.
-                return unsafe { lang_from_parts(tables::SCRIPT_REGION[r].2, None, None, None) };
+                // safe because all table entries are validated.
+                return unsafe { lang_from_parts(data.script_region[r].2, None, None, None) };
             }
         }
 
-        let result = tables::SCRIPT_ONLY
+        let result = data
+            .script_only
             .binary_search_by_key(&(&s.into()), |(key_s, _)| key_s)
             .ok();
         if let Some(r) = result {
-            // safe because all table entries are well formed.
-            return unsafe { lang_from_parts(tables::SCRIPT_ONLY[r].1, None, None, region) };
+            // safe because all table entries are validated.
+            return unsafe { lang_from_parts(data.script_only[r].1, None, None, region) };
         }
     } else if let Some(r) = region {
-        let result = tables::REGION_ONLY
+        let result = data
+            .region_only
             .binary_search_by_key(&(&r.into()), |(key_r, _)| key_r)
             .ok();
         if let Some(r) = result {
-            // safe because all table entries are well formed.
-            return unsafe { lang_from_parts(tables::REGION_ONLY[r].1, None, None, None) };
+            // safe because all table entries are validated.
+            return unsafe { lang_from_parts(data.region_only[r].1, None, None, None) };
+        }
+    }
+
+    if lang.is_empty() {
+        // Fall back on the likely subtags of `und`, keeping the script
+        // and region of the input.
+        let result = data
+            .lang_only
+            .binary_search_by_key(&&UND, |(key_l, _)| key_l)
+            .ok();
+        if let Some(r) = result {
+            // safe because all table entries are validated.
+            return unsafe { lang_from_parts(data.lang_only[r].1, None, script, region) };
         }
     }
 
     None
 }
 
-pub fn minimize(
+fn maximal(
+    data: &LikelySubtagsData,
     lang: subtags::Language,
     script: Option<subtags::Script>,
     region: Option<subtags::Region>,
@@ -105,20 +268,46 @@ pub fn minimize(
 )> {
     // maximize returns None when all 3 components are
     // already filled so don't call it in that case.
-    let max_langid = if !lang.is_empty() && script.is_some() && region.is_some() {
-        (lang, script, region)
+    if !lang.is_empty() && script.is_some() && region.is_some() {
+        Some((lang, script, region))
     } else {
-        maximize(lang, script, region)?
-    };
+        maximize_with(data, lang, script, region)
+    }
+}
 
-    if let Some(trial) = maximize(max_langid.0, None, None) {
+pub fn minimize(
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<(
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+)> {
+    minimize_with(&CLDR, lang, script, region)
+}
+
+/// Like `minimize`, using the given likely subtags data.
+pub fn minimize_with(
+    data: &LikelySubtagsData,
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<(
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+)> {
+    let max_langid = maximal(data, lang, script, region)?;
+
+    if let Some(trial) = maximize_with(data, max_langid.0, None, None) {
         if trial == max_langid {
             return Some((max_langid.0, None, None));
         }
     }
 
     if max_langid.2.is_some() {
-        if let Some(trial) = maximize(max_langid.0, None, max_langid.2) {
+        if let Some(trial) = maximize_with(data, max_langid.0, None, max_langid.2) {
             if trial == max_langid {
                 return Some((max_langid.0, None, max_langid.2));
             }
@@ -126,7 +315,7 @@ pub fn minimize(
     }
 
     if max_langid.1.is_some() {
-        if let Some(trial) = maximize(max_langid.0, max_langid.1, None) {
+        if let Some(trial) = maximize_with(data, max_langid.0, max_langid.1, None) {
             if trial == max_langid {
                 return Some((max_langid.0, max_langid.1, None));
             }
@@ -134,3 +323,62 @@ pub fn minimize(
     }
     None
 }
+
+pub fn minimize_keeping_script(
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<(
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+)> {
+    let max_langid = maximal(&CLDR, lang, script, region)?;
+
+    if let Some(trial) = maximize_with(&CLDR, max_langid.0, max_langid.1, None) {
+        if trial == max_langid {
+            return Some((max_langid.0, max_langid.1, None));
+        }
+    }
+    None
+}
+
+/// Returns the likely script of a raw language subtag, such as `sr`, based
+/// on the embedded CLDR likely subtags data for the language alone.
+///
+/// This is cheaper than maximizing a `LanguageIdentifier`, as only a single
+/// table is consulted. Returns `None` for malformed or unknown languages.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::likelysubtags::default_script_for;
+///
+/// assert_eq!(default_script_for("sr").unwrap(), "Cyrl");
+/// assert_eq!(default_script_for("EN").unwrap(), "Latn");
+/// assert_eq!(default_script_for("zxx"), None);
+/// ```
+pub fn default_script_for(lang: &str) -> Option<subtags::Script> {
+    let lang = subtags::Language::from_bytes(lang.as_bytes()).ok()?;
+    let key = lang.as_u64().unwrap_or(UND);
+    let idx = CLDR
+        .lang_only
+        .binary_search_by_key(&key, |(key_l, _)| *key_l)
+        .ok()?;
+    let (_, (_, script, _)) = CLDR.lang_only[idx];
+    // safe because all table entries are validated.
+    script.map(|s| unsafe { subtags::Script::from_raw_unchecked(s) })
+}
+
+#[test]
+fn cldr_data_is_valid() {
+    assert!(LikelySubtagsData::new(
+        &tables::LANG_ONLY,
+        &tables::LANG_REGION,
+        &tables::LANG_SCRIPT,
+        &tables::SCRIPT_REGION,
+        &tables::SCRIPT_ONLY,
+        &tables::REGION_ONLY,
+    )
+    .is_ok());
+}
@@ -0,0 +1,143 @@
+//! Language negotiation between a list of requested and a list of available
+//! language identifiers, following the filtering, matching and lookup schemes
+//! of [`RFC 4647`].
+//!
+//! [`RFC 4647`]: https://tools.ietf.org/html/rfc4647
+use crate::LanguageIdentifier;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The strategy used by `negotiate_languages`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegotiationStrategy {
+    /// Returns all available identifiers matching any of the requested ones,
+    /// ordered by the requested identifier they match.
+    Filtering,
+    /// Returns the best available identifier for each requested one.
+    Matching,
+    /// Returns a single identifier, the best match for the first requested
+    /// identifier which has any match, or the default.
+    Lookup,
+}
+
+/// Negotiates between an ordered list of `requested` language identifiers and
+/// a list of `available` ones.
+///
+/// For each requested identifier, available identifiers are tried in order of
+/// decreasing specificity:
+///
+/// 1. An exact match.
+/// 2. The available identifier treated as a range, so `en` matches `en-US`.
+/// 3. The maximized requested identifier, so `en` matches `en-Latn-US`.
+/// 4. The maximized requested identifier without variants.
+/// 5. The maximized requested identifier with the region of its likely
+///    subtags, so `en-GB` matches `en-US`.
+/// 6. The requested identifier with the region treated as a range.
+///
+/// The `default` is appended to the result unless it is already present.
+/// With the `Lookup` strategy the `default` is only returned when nothing
+/// else matched.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{negotiate_languages, LanguageIdentifier, NegotiationStrategy};
+///
+/// let requested: Vec<LanguageIdentifier> = vec!["de-AT".parse().expect("Parsing failed."),
+///                                               "en".parse().expect("Parsing failed.")];
+/// let available: Vec<LanguageIdentifier> = vec!["en-US".parse().expect("Parsing failed."),
+///                                               "de-DE".parse().expect("Parsing failed."),
+///                                               "fr".parse().expect("Parsing failed.")];
+///
+/// let supported = negotiate_languages(
+///     &requested,
+///     &available,
+///     None,
+///     NegotiationStrategy::Filtering,
+/// );
+/// assert_eq!(supported, vec![&available[1], &available[0]]);
+/// ```
+pub fn negotiate_languages<'a>(
+    requested: &[LanguageIdentifier],
+    available: &'a [LanguageIdentifier],
+    default: Option<&'a LanguageIdentifier>,
+    strategy: NegotiationStrategy,
+) -> Vec<&'a LanguageIdentifier> {
+    let mut supported = filter_matches(requested, available, strategy);
+
+    if let Some(default) = default {
+        if strategy == NegotiationStrategy::Lookup {
+            if supported.is_empty() {
+                supported.push(default);
+            }
+        } else if !supported.contains(&default) {
+            supported.push(default);
+        }
+    }
+    supported
+}
+
+fn filter_matches<'a>(
+    requested: &[LanguageIdentifier],
+    available: &'a [LanguageIdentifier],
+    strategy: NegotiationStrategy,
+) -> Vec<&'a LanguageIdentifier> {
+    let mut supported = vec![];
+
+    // Each candidate is paired with its maximized form, computed once.
+    let mut candidates: Vec<(&LanguageIdentifier, LanguageIdentifier)> = available
+        .iter()
+        .map(|langid| (langid, langid.maximized()))
+        .collect();
+
+    for req in requested {
+        let mut found = false;
+
+        macro_rules! test_strategy {
+            ($test:expr) => {
+                candidates.retain(|(langid, maximized)| {
+                    if strategy != NegotiationStrategy::Filtering && found {
+                        return true;
+                    }
+                    #[allow(clippy::redundant_closure_call)]
+                    let matches = ($test)(*langid, maximized);
+                    if matches {
+                        supported.push(*langid);
+                        found = true;
+                    }
+                    !matches
+                });
+            };
+        }
+
+        // 1) Exact match.
+        test_strategy!(|langid: &LanguageIdentifier, _| langid == req);
+
+        // 2) Available identifier treated as a range.
+        test_strategy!(|langid: &LanguageIdentifier, _| langid.matches(req, true, false));
+
+        // 3) Maximized requested identifier.
+        let mut req = req.maximized();
+        test_strategy!(|langid: &LanguageIdentifier, _| langid.matches(&req, true, false));
+
+        // 4) Maximized requested identifier without variants.
+        req.clear_variants();
+        test_strategy!(|langid: &LanguageIdentifier, _| langid.matches(&req, true, false));
+
+        // 5) Maximized requested identifier with its likely region.
+        req.region = None;
+        if req.maximize() {
+            test_strategy!(|_, maximized: &LanguageIdentifier| maximized.matches(&req, false, true));
+        }
+
+        // 6) Requested identifier with the region treated as a range.
+        req.region = None;
+        test_strategy!(|_, maximized: &LanguageIdentifier| maximized.matches(&req, false, true));
+
+        if found && strategy == NegotiationStrategy::Lookup {
+            break;
+        }
+    }
+
+    supported
+}
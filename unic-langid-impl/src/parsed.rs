@@ -0,0 +1,133 @@
+use crate::aliases;
+use crate::errors::LanguageIdentifierError;
+use crate::LanguageIdentifier;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// Options controlling how `LanguageIdentifier::parse_with_options`
+/// serializes the parsed input.
+///
+/// The options never affect the parsed `LanguageIdentifier` itself, which is
+/// always canonical. The default options match `from_str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// Convert every subtag to its canonical casing. When disabled, the
+    /// casing of the input is preserved.
+    pub normalize_case: bool,
+    /// Sort variants in their canonical order. When disabled, the order of
+    /// the input is preserved.
+    pub reorder_variants: bool,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            normalize_case: true,
+            reorder_variants: true,
+        }
+    }
+}
+
+/// A canonical `LanguageIdentifier` together with a serialization of the
+/// input it was parsed from, as returned by `LanguageIdentifier::parse_with_options`.
+///
+/// It dereferences to the canonical `LanguageIdentifier`, so comparisons and
+/// subtag accessors operate on canonical forms, while `Display` and `as_str`
+/// show the input with the casing and variant order kept according to the
+/// `ParserOptions`. Subtags are always separated by `-`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+///
+/// let options = ParserOptions {
+///     normalize_case: false,
+///     reorder_variants: false,
+/// };
+/// let li = LanguageIdentifier::parse_with_options("EN_us_POSIX_1996", options)
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li.to_string(), "EN-us-POSIX-1996");
+/// assert_eq!(li.language, "en");
+/// assert_eq!(*li, "en-US-1996-posix");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedLanguageIdentifier {
+    langid: LanguageIdentifier,
+    source: String,
+}
+
+impl ParsedLanguageIdentifier {
+    pub(crate) fn parse(
+        input: &str,
+        options: ParserOptions,
+    ) -> Result<Self, LanguageIdentifierError> {
+        let langid: LanguageIdentifier = input.parse()?;
+
+        let mut subtags: Vec<&str> = input.split(['-', '_']).collect();
+        let variants_start =
+            1 + langid.script.is_some() as usize + langid.region.is_some() as usize;
+
+        // Grandfathered tags are replaced while parsing, so the subtags of
+        // the input don't correspond to the identifier.
+        if aliases::grandfathered(input.as_bytes()).is_some()
+            || subtags.len() != variants_start + langid.variant_count()
+        {
+            return Ok(Self {
+                source: langid.to_string(),
+                langid,
+            });
+        }
+
+        if options.reorder_variants {
+            subtags[variants_start..].sort_unstable_by_key(|v| v.to_ascii_lowercase());
+        }
+
+        let source = if options.normalize_case {
+            let canonical: Vec<&str> = langid.subtags().take(variants_start).collect();
+            let mut source = canonical.join("-");
+            for variant in &subtags[variants_start..] {
+                source.push('-');
+                source.push_str(&variant.to_ascii_lowercase());
+            }
+            source
+        } else {
+            subtags.join("-")
+        };
+
+        Ok(Self { langid, source })
+    }
+
+    /// Returns the serialized input.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Consumes the `ParsedLanguageIdentifier` and returns the canonical
+    /// `LanguageIdentifier`.
+    pub fn into_langid(self) -> LanguageIdentifier {
+        self.langid
+    }
+}
+
+impl Deref for ParsedLanguageIdentifier {
+    type Target = LanguageIdentifier;
+
+    fn deref(&self) -> &LanguageIdentifier {
+        &self.langid
+    }
+}
+
+impl AsRef<LanguageIdentifier> for ParsedLanguageIdentifier {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        &self.langid
+    }
+}
+
+impl core::fmt::Display for ParsedLanguageIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
@@ -1,20 +1,24 @@
-use std::error::Error;
-use std::fmt::{self, Display};
+use crate::subtags::Variant;
+use core::fmt::{self, Display};
 
 #[derive(Debug, PartialEq)]
 pub enum ParserError {
     InvalidLanguage,
+    InvalidRegion,
     InvalidSubtag,
+    DuplicateVariant(Variant),
 }
 
-impl Error for ParserError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParserError {}
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let value = match self {
-            ParserError::InvalidLanguage => "The given language subtag is invalid",
-            ParserError::InvalidSubtag => "Invalid subtag",
-        };
-        f.write_str(value)
+        match self {
+            ParserError::InvalidLanguage => f.write_str("The given language subtag is invalid"),
+            ParserError::InvalidRegion => f.write_str("The given region subtag is invalid"),
+            ParserError::InvalidSubtag => f.write_str("Invalid subtag"),
+            ParserError::DuplicateVariant(v) => write!(f, "Duplicate variant subtag: {}", v),
+        }
     }
 }
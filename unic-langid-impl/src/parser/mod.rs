@@ -1,8 +1,10 @@
 pub mod errors;
 
-use std::iter::Peekable;
+use alloc::vec;
+use core::iter::Peekable;
 
 pub use self::errors::ParserError;
+use crate::aliases;
 use crate::subtags;
 use crate::LanguageIdentifier;
 
@@ -22,37 +24,33 @@ pub fn parse_language_identifier_from_iter<'a>(
 
     let mut position = 1;
 
+    // Each subtag is classified by its length first, so that it is only
+    // parsed as the one subtag type it can be in its position.
     while let Some(subtag) = iter.peek() {
-        if position == 1 {
+        let slen = subtag.len();
+        if position == 1 && slen == 4 && subtag[0].is_ascii_alphabetic() {
             if let Ok(s) = subtags::Script::from_bytes(subtag) {
                 script = Some(s);
                 position = 2;
-            } else if let Ok(s) = subtags::Region::from_bytes(subtag) {
-                region = Some(s);
-                position = 3;
-            } else if let Ok(v) = subtags::Variant::from_bytes(subtag) {
-                variants.push(v);
-                position = 3;
             } else {
                 break;
             }
-        } else if position == 2 {
+        } else if position < 3 && (slen == 2 || slen == 3) {
             if let Ok(s) = subtags::Region::from_bytes(subtag) {
                 region = Some(s);
                 position = 3;
-            } else if let Ok(v) = subtags::Variant::from_bytes(subtag) {
-                variants.push(v);
-                position = 3;
             } else {
                 break;
             }
-        } else {
-            // Variants
+        } else if (4..=8).contains(&slen) {
             if let Ok(v) = subtags::Variant::from_bytes(subtag) {
                 variants.push(v);
+                position = 3;
             } else {
                 break;
             }
+        } else {
+            break;
         }
         iter.next();
     }
@@ -65,7 +63,9 @@ pub fn parse_language_identifier_from_iter<'a>(
         None
     } else {
         variants.sort_unstable();
-        variants.dedup();
+        if let Some(w) = variants.windows(2).find(|w| w[0] == w[1]) {
+            return Err(ParserError::DuplicateVariant(w[0]));
+        }
         Some(variants.into_boxed_slice())
     };
 
@@ -77,7 +77,21 @@ pub fn parse_language_identifier_from_iter<'a>(
     })
 }
 
+/// Returns the preferred value of a BCP 47 grandfathered tag, such as
+/// `i-klingon` or `zh-guoyu`, or `None` if `t` is not one.
+///
+/// Grandfathered tags only match the whole input, so parsers check them
+/// before splitting the input into subtags.
+pub fn parse_grandfathered(t: &[u8]) -> Option<LanguageIdentifier> {
+    let preferred = aliases::grandfathered(t)?;
+    let mut iter = preferred.as_bytes().split(|c| *c == b'-').peekable();
+    parse_language_identifier_from_iter(&mut iter, false).ok()
+}
+
 pub fn parse_language_identifier(t: &[u8]) -> Result<LanguageIdentifier, ParserError> {
+    if let Some(langid) = parse_grandfathered(t) {
+        return Ok(langid);
+    }
     let mut iter = t.split(|c| *c == b'-' || *c == b'_').peekable();
     parse_language_identifier_from_iter(&mut iter, false)
 }
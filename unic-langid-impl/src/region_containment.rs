@@ -0,0 +1,129 @@
+#![allow(clippy::unreadable_literal)]
+
+pub static REGION_CONTAINMENT: [(u32, &[u32]); 32] = [
+    (20305, &[17217, 20801, 20547, 18244, 16724]),
+    (
+        3158832,
+        &[20035, 19272, 20554, 20555, 21067, 20045, 20301, 22356],
+    ),
+    (3159345, &[3748656, 3224881, 3421489, 3487025]),
+    (3223600, &[3289136, 3747888, 3748144, 3290161, 3159345]),
+    (
+        3223856,
+        &[
+            17986, 19010, 18755, 22083, 18503, 19783, 20039, 22343, 21068, 19533, 21069, 17742,
+            18254, 18515, 19539, 20051, 18260,
+        ],
+    ),
+    (3224112, &[19778, 16707, 19527, 19792, 21333]),
+    (
+        3224881,
+        &[
+            18242, 22850, 23107, 21832, 17485, 19536, 20306, 21842, 19283, 16725,
+        ],
+    ),
+    (
+        3225136,
+        &[
+            21313, 19267, 21838, 18000, 20048, 19284, 20308, 22100, 18007, 21335,
+        ],
+    ),
+    (
+        3289136,
+        &[3223856, 3420464, 3486000, 3617072, 3682608, 3289138],
+    ),
+    (3289138, &[3223856, 3420464, 3617072, 3682608]),
+    (3290161, &[3158832, 3420976, 3486512, 3355697, 3486769]),
+    (3354672, &[3354928, 3224112, 3748400]),
+    (
+        3354928,
+        &[23106, 21059, 21575, 20040, 22605, 18766, 16720, 22099],
+    ),
+    (3355697, &[18251, 23115, 19028, 19796, 23125]),
+    (3355952, &[21825, 17219, 22595, 19784, 17998, 23118]),
+    (
+        3420464,
+        &[
+            18754, 19012, 21061, 21573, 20297, 17739, 19787, 18253, 21837, 22349, 23117, 17746,
+            22354, 17235, 20307, 21331, 18004, 23124, 18261, 21593, 19802, 22362,
+        ],
+    ),
+    (
+        3420976,
+        &[
+            17985, 17474, 21570, 20041, 21065, 19276, 22093, 20558, 19280,
+        ],
+    ),
+    (3421488, &[19014, 17230, 18256, 16979, 21846]),
+    (
+        3421489,
+        &[
+            22593, 20803, 19268, 17733, 18758, 20294, 16967, 18247, 17737, 19785, 21321, 17738,
+            21580, 22092, 20302, 17747, 19027,
+        ],
+    ),
+    (
+        3485744,
+        &[
+            21057, 20290, 21058, 22082, 19523, 20291, 17221, 19270, 17991, 21319, 22855, 17744,
+            22864, 21075, 22869, 17750,
+        ],
+    ),
+    (
+        3486000,
+        &[
+            23108, 16709, 18245, 18501, 17225, 22860, 16717, 17491, 20052,
+        ],
+    ),
+    (
+        3486512,
+        &[
+            20034, 17481, 18507, 16716, 19789, 22861, 18512, 18259, 18516, 19540, 20054,
+        ],
+    ),
+    (
+        3486769,
+        &[
+            17729, 19777, 23105, 18498, 22851, 17735, 19529, 20809, 20298, 22347, 16972, 19791,
+            21328, 16721, 16723, 22867, 21076, 17753,
+        ],
+    ),
+    (
+        3487025,
+        &[
+            21569, 17730, 18499, 17732, 21062, 18764, 21836, 17229, 19534,
+        ],
+    ),
+    (
+        3617072,
+        &[
+            20289, 17475, 17987, 18243, 19779, 16711, 20807, 21587, 17492,
+        ],
+    ),
+    (
+        3618096,
+        &[19782, 21831, 18763, 18509, 20557, 21070, 22352, 19797],
+    ),
+    (3682608, &[22338, 21324, 16718, 23123, 16730]),
+    (3747888, &[3355952, 3421488, 3618096, 3225136, 20305]),
+    (
+        3748144,
+        &[3354672, 3485744, 3354928, 3224112, 3748400, 3748148],
+    ),
+    (3748148, &[3485744, 3354928, 3748400]),
+    (
+        3748400,
+        &[
+            18241, 18753, 22337, 16962, 19522, 20802, 21314, 21827, 22339, 19780, 20292, 17479,
+            20551, 21576, 19786, 20043, 22859, 17228, 17997, 20813, 21325, 21072, 22611, 17236,
+            21588, 17238, 18262, 18774,
+        ],
+    ),
+    (
+        3748656,
+        &[
+            17473, 19521, 16706, 21317, 18759, 21063, 21064, 21577, 17741, 19277, 21581, 21584,
+            21330, 18771, 19795, 16726, 19288,
+        ],
+    ),
+];
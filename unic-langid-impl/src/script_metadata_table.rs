@@ -0,0 +1,184 @@
+#![allow(clippy::type_complexity)]
+#![allow(clippy::unreadable_literal)]
+
+pub static SCRIPT_METADATA: [(
+    u32,
+    Option<u32>,
+    char,
+    bool,
+    Option<bool>,
+    bool,
+    Option<u32>,
+    Option<u64>,
+); 170] = [
+    (1633773652, None, 'ތ', true, None, false, None, None),
+    (1633840197, None, '𐔀', false, None, false, None, None),
+    (1633971787, None, 'ಕ', false, None, false, None, None),
+    (1634427213, None, '𑻥', false, None, false, None, None),
+    (1634562895, None, '𐒀', false, None, false, None, None),
+    (1634623819, None, 'カ', false, None, false, None, None),
+    (1634623820, None, 'ᨠ', false, None, false, None, None),
+    (1634625868, None, '𐙇', false, None, false, None, None),
+    (1634885975, None, '𑢴', false, None, true, None, None),
+    (1634888008, None, 'か', false, None, false, None, None),
+    (1634889555, None, '𑃐', false, None, false, None, None),
+    (1634954836, None, '𖪼', false, None, false, None, None),
+    (1635148106, None, 'ꦄ', false, None, false, None, None),
+    (1635149124, None, 'अ', false, None, false, None, None),
+    (1635214670, None, '𑐒', false, None, false, None, None),
+    (1635349071, None, 'କ', false, None, false, None, None),
+    (1650553409, None, 'ب', true, None, false, None, None),
+    (1650942292, None, 'ᝣ', false, None, false, None, None),
+    (1651009345, None, '𐔷', false, None, false, None, None),
+    (1651337548, None, 'ᤀ', false, None, false, None, None),
+    (1651401032, None, '字', false, None, false, None, None),
+    (1651401050, None, '𑨋', false, None, false, None, None),
+    (1651403084, None, '𐀀', false, None, false, None, None),
+    (1651663182, None, '𐪕', true, None, false, None, None),
+    (1651663187, None, '𐩠', true, None, false, None, None),
+    (1668310348, None, 'ᰀ', false, None, false, None, None),
+    (1668440397, None, '𑱲', false, None, false, None, None),
+    (1668441421, None, '𐦠', true, None, false, None, None),
+    (1668446547, None, 'ܐ', true, None, false, None, None),
+    (1668637008, None, '𑫀', false, None, false, None, None),
+    (1684302163, None, '𑖎', false, None, false, None, None),
+    (1684500307, None, '𐽂', true, None, false, None, None),
+    (1684567362, None, 'ᝃ', false, None, false, None, None),
+    (1684955469, None, 'ࡀ', true, None, false, None, None),
+    (1684955470, None, '𑧎', false, None, false, None, None),
+    (1684956493, None, '𞠂', true, None, false, None, None),
+    (1684957523, None, '𑊾', false, None, false, None, None),
+    (1684960595, None, 'ᮃ', false, None, false, None, None),
+    (1685219411, None, '𑆃', false, None, false, None, None),
+    (1685220432, None, '𖼀', false, None, false, None, None),
+    (1701278543, None, '𐒵', false, None, true, None, None),
+    (1701601620, None, 'ᥐ', false, None, false, None, None),
+    (1701998411, None, '가', false, None, false, None, None),
+    (1717855565, None, '𖹀', false, None, true, None, None),
+    (1734436944, None, 'ꡀ', false, None, false, None, None),
+    (1734437959, None, 'Ⰰ', false, None, true, None, None),
+    (1734897490, None, '𐴒', true, None, false, None, None),
+    (1735157588, None, 'ᜃ', false, None, false, None, None),
+    (1735287112, None, '가', false, None, false, None, None),
+    (1735287124, None, '𘈩', false, None, false, None, None),
+    (1735288130, None, 'ক', false, None, false, None, None),
+    (1735288404, None, 'ⴰ', false, None, false, None, None),
+    (1735289426, None, 'ꤰ', false, None, false, None, None),
+    (1735290184, None, '𖬜', false, None, false, None, None),
+    (1735290695, None, '𑵱', false, None, false, None, None),
+    (1735290701, None, 'ᠦ', false, None, false, None, None),
+    (1735292232, None, '𐲡', true, None, true, None, None),
+    (1751216706, None, '𑀅', false, None, false, None, None),
+    (1751872079, None, '𐰀', true, None, false, None, None),
+    (1752066387, None, 'අ', false, None, false, None, None),
+    (1752066394, None, '\u{308}', false, None, false, None, None),
+    (1752328532, None, '𑒄', false, None, false, None, None),
+    (1752459606, None, '𐖂', false, None, true, None, None),
+    (1752461127, None, '𐌰', false, None, false, None, None),
+    (1767991380, None, 'ท', false, None, false, None, None),
+    (1767993922, None, '⠎', false, None, false, None, None),
+    (1768126796, None, '𐊀', false, None, false, None, None),
+    (1768189773, None, '𑘎', false, None, false, None, None),
+    (1768192332, None, '𐤠', true, None, false, None, None),
+    (1768256589, None, 'ꯀ', false, None, false, None, None),
+    (1768387906, None, 'ᨀ', false, None, false, None, None),
+    (1768453189, None, 'አ', false, None, false, None, None),
+    (1768453195, None, '𑂃', false, None, false, None, None),
+    (1768513878, None, 'ꕉ', false, None, false, None, None),
+    (1768515929, None, 'ꊈ', false, None, false, None, None),
+    (1768710466, None, 'ᬅ', false, None, false, None, None),
+    (1768710475, None, 'ꤊ', false, None, false, None, None),
+    (1768712272, None, '𐭠', true, None, false, None, None),
+    (1768780353, None, '𐡀', true, None, false, None, None),
+    (1768841544, None, '字', false, None, false, None, None),
+    (1768841549, None, '𐫘', true, None, false, None, None),
+    (1769103683, None, '𐊠', false, None, false, None, None),
+    (1769239120, None, '𐭀', true, None, false, None, None),
+    (1769431371, None, '𑼛', false, None, false, None, None),
+    (1769629017, None, '𐺈', true, None, false, None, None),
+    (1785225549, None, '𑅒', false, None, false, None, None),
+    (1785686091, None, '𑈈', false, None, false, None, None),
+    (1801546052, None, '𑤌', false, None, false, None, None),
+    (1801677903, None, 'ᱚ', false, None, false, None, None),
+    (1801810503, None, 'Ω', false, None, true, None, None),
+    (1802789186, None, 'ᯀ', false, None, false, None, None),
+    (1818326089, None, '𐌀', false, None, false, None, None),
+    (1819107668, None, 'க', false, None, false, None, None),
+    (1819309380, None, '𛰠', false, None, false, None, None),
+    (1819441475, None, 'Я', false, None, true, None, None),
+    (1835099983, None, 'ᚏ', false, None, false, None, None),
+    (1835100227, None, 'ꨀ', false, None, false, None, None),
+    (1835491662, None, '𞓦', false, None, false, None, None),
+    (1835753795, None, '𑄃', false, None, false, None, None),
+    (1835819344, None, '𐡳', true, None, false, None, None),
+    (1835820097, None, '𞤉', true, None, true, None, None),
+    (1835953991, None, '𑴐', false, None, false, None, None),
+    (1836017729, None, '𑜗', false, None, false, None, None),
+    (1836213584, None, '𐍫', false, None, false, None, None),
+    (1836674117, None, '𐿱', true, None, false, None, None),
+    (1836674125, None, 'ക', false, None, false, None, None),
+    (1851879498, None, 'か', false, None, false, None, None),
+    (1851880007, None, '𑌕', false, None, false, None, None),
+    (1852665923, None, '𒿥', false, None, false, None, None),
+    (1852666433, None, 'Ա', false, None, true, None, None),
+    (1853120844, None, 'L', false, None, true, None, None),
+    (1868918872, None, '𐎠', false, None, false, None, None),
+    (1869049683, None, '𐼙', true, None, false, None, None),
+    (1869112151, None, '𞋡', false, None, false, None, None),
+    (1869379923, None, 'ꠀ', false, None, false, None, None),
+    (1869439306, None, 'ᄒ', false, None, false, None, None),
+    (1869504840, None, 'ᜣ', false, None, false, None, None),
+    (1869570380, None, 'ລ', false, None, false, None, None),
+    (1869572942, None, 'ߊ', true, None, false, None, None),
+    (1869574733, None, '𖩏', false, None, false, None, None),
+    (1869639490, None, 'ㄅ', false, None, false, None, None),
+    (1869768013, None, '𐦀', true, None, false, None, None),
+    (1869901652, None, '𞊐', false, None, false, None, None),
+    (1870229331, None, '𑩜', false, None, false, None, None),
+    (1886152784, None, '𐮏', true, None, false, None, None),
+    (1886285128, None, '𞄈', false, None, false, None, None),
+    (1887004485, None, '𓅓', false, None, false, None, None),
+    (1918986069, None, '𐎀', false, None, false, None, None),
+    (1918986315, None, '𐨀', true, None, false, None, None),
+    (1919051080, None, 'א', true, None, false, None, None),
+    (1919248451, None, 'Ꮔ', false, None, true, None, None),
+    (1919381316, None, '𑠋', false, None, false, None, None),
+    (1919382863, None, '𐽼', true, None, false, None, None),
+    (1919579463, None, 'ક', false, None, false, None, None),
+    (1919639892, None, '𑚀', false, None, false, None, None),
+    (1919770963, None, 'ࠀ', true, None, false, None, None),
+    (1919772747, None, 'ក', false, None, false, None, None),
+    (1919777101, None, 'က', false, None, false, None, None),
+    (1919841618, None, 'ᚠ', false, None, false, None, None),
+    (1919903047, None, 'დ', false, None, false, None, None),
+    (1920229704, None, '𐣴', true, None, false, None, None),
+    (1920295251, None, 'ꢂ', false, None, false, None, None),
+    (1936418882, None, '𑰎', false, None, false, None, None),
+    (1936613699, None, 'ᓀ', false, None, false, None, None),
+    (1936613704, None, '字', false, None, false, None, None),
+    (1936877635, None, '𐾿', true, None, false, None, None),
+    (1936941378, None, '𖫦', false, None, false, None, None),
+    (1937008971, None, '𘱥', false, None, false, None, None),
+    (1952539214, None, '𐢖', true, None, false, None, None),
+    (1952606548, None, 'ཀ', false, None, false, None, None),
+    (1953264973, None, '𑊏', false, None, false, None, None),
+    (1953390920, None, '字', false, None, false, None, None),
+    (1953525571, None, 'Ϣ', false, None, true, None, None),
+    (1953656899, None, '𐠀', true, None, false, None, None),
+    (1953657668, None, '𐐔', false, None, true, None, None),
+    (1953723969, None, '𐬀', true, None, false, None, None),
+    (1953915220, None, 'ꪀ', false, None, false, None, None),
+    (1969779534, None, '𛇄', false, None, false, None, None),
+    (1970037076, None, 'ᦀ', false, None, false, None, None),
+    (1970038100, None, 'క', false, None, false, None, None),
+    (1970102594, None, 'ꚠ', false, None, false, None, None),
+    (1970435399, None, 'ਕ', false, None, false, None, None),
+    (1970497868, None, 'ꓐ', false, None, false, None, None),
+    (2002872403, None, '𐑐', false, None, false, None, None),
+    (2003724115, None, '𝡐', false, None, false, None, None),
+    (2004184136, None, '𔐀', false, None, false, None, None),
+    (2020501584, None, '𐤀', true, None, false, None, None),
+    (2020963160, None, '𒀀', false, None, false, None, None),
+    (2038004058, None, '@', false, None, false, None, None),
+    (2054847066, None, '\u{fdd0}', false, None, false, None, None),
+];
@@ -1,4 +1,7 @@
 use crate::LanguageIdentifier;
+#[cfg(test)]
+use alloc::boxed::Box;
+use alloc::string::ToString;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 impl Serialize for LanguageIdentifier {
@@ -20,7 +23,7 @@ impl<'de> Deserialize<'de> for LanguageIdentifier {
         impl<'de> serde::de::Visitor<'de> for LanguageIdentifierVisitor {
             type Value = LanguageIdentifier;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(formatter, "A valid Unicode Language Identifier")
             }
 
@@ -56,5 +59,8 @@ fn deserialize() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(fr, "fr".parse::<LanguageIdentifier>()?);
     assert_eq!(en, "en-US".parse::<LanguageIdentifier>()?);
 
+    assert!(serde_json::from_str::<LanguageIdentifier>(r#""en-US-""#).is_err());
+    assert!(serde_json::from_str::<LanguageIdentifier>(r#""""#).is_err());
+
     Ok(())
 }
@@ -1,9 +1,25 @@
 use crate::parser::errors::ParserError;
-use std::borrow::Borrow;
-use std::convert::TryFrom;
-use std::str::FromStr;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::str::FromStr;
 use tinystr::TinyStr8;
 
+/// A language subtag (examples: `"en"`, `"csb"`, `"zh"`, `"und"`).
+///
+/// An empty `Language` represents the `und` (undefined) subtag.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::Language;
+///
+/// let language: Language = "EN".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(language, "en");
+/// assert_eq!(language.as_str(), "en");
+/// assert_eq!(language.to_string(), "en");
+/// ```
 #[derive(Default, Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
 pub struct Language(Option<TinyStr8>);
 
@@ -11,8 +27,12 @@ impl Language {
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
         let slen = v.len();
 
+        if !(2..=8).contains(&slen) || slen == 4 {
+            return Err(ParserError::InvalidLanguage);
+        }
+
         let s = TinyStr8::from_bytes(v).map_err(|_| ParserError::InvalidLanguage)?;
-        if slen < 2 || slen > 8 || slen == 4 || !s.is_ascii_alphabetic() {
+        if !s.is_ascii_alphabetic() {
             return Err(ParserError::InvalidLanguage);
         }
 
@@ -29,6 +49,45 @@ impl Language {
         self.0.as_deref().unwrap_or("und")
     }
 
+    /// Returns the packed integer representation of the subtag, or `None`
+    /// for `und`.
+    ///
+    /// The value is the little-endian integer of the canonically cased ASCII bytes, padded
+    /// with zero bytes, and is stable across releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// let language: Language = "EN".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(language.as_u64(), Some(u64::from_le_bytes(*b"en\0\0\0\0\0\0")));
+    /// assert_eq!(Language::default().as_u64(), None);
+    /// ```
+    pub fn as_u64(self) -> Option<u64> {
+        self.into()
+    }
+
+    /// Returns the `und` (undefined) language subtag.
+    ///
+    /// Unlike `Language::default()`, this can be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// const UND: Language = Language::und();
+    ///
+    /// assert_eq!(UND, "und");
+    /// assert!(UND.is_empty());
+    /// ```
+    pub const fn und() -> Self {
+        Self(None)
+    }
+
     /// # Safety
     ///
     /// This function accepts any u64 that is exected to be a valid
@@ -69,6 +128,12 @@ impl From<&Language> for Option<u64> {
     }
 }
 
+impl<'l> From<&'l Language> for &'l str {
+    fn from(input: &'l Language) -> Self {
+        input.as_str()
+    }
+}
+
 impl<T> TryFrom<Option<T>> for Language
 where
     T: AsRef<[u8]>,
@@ -91,8 +156,8 @@ impl FromStr for Language {
     }
 }
 
-impl std::fmt::Display for Language {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Language {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(ref lang) = self.0 {
             f.write_str(lang)
         } else {
@@ -1,3 +1,7 @@
+//! Language Identifier subtags.
+//!
+//! Each subtag is a validated, normalized newtype which can only be
+//! constructed by parsing, so holding one guarantees a well-formed value.
 mod language;
 mod region;
 mod script;
@@ -6,4 +10,6 @@ mod variant;
 pub use language::Language;
 pub use region::Region;
 pub use script::Script;
+#[cfg(feature = "direction")]
+pub use script::ScriptMetadata;
 pub use variant::Variant;
@@ -1,30 +1,62 @@
 use crate::parser::errors::ParserError;
-use std::str::FromStr;
+use crate::region_containment::REGION_CONTAINMENT;
+use core::str::FromStr;
 use tinystr::TinyStr4;
 
+/// A region subtag (examples: `"US"`, `"CN"`, `"419"`).
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::Region;
+///
+/// let region: Region = "fr".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(region, "FR");
+/// assert_eq!(region.as_str(), "FR");
+/// ```
 #[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
 pub struct Region(TinyStr4);
 
 impl Region {
+    /// Parses a bare region subtag, without a full language identifier.
+    ///
+    /// Both two letter ISO 3166-1 codes, normalized to upper case, and
+    /// three digit UN M.49 codes are accepted. This is the same as parsing
+    /// with `str::parse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    ///
+    /// let region = Region::from_bytes(b"gb")
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(region, "GB");
+    /// assert_eq!(region, "GB".parse::<Region>().expect("Parsing failed."));
+    /// assert!(Region::from_bytes(b"GBR").is_err());
+    /// ```
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
         let slen = v.len();
 
         match slen {
             2 => {
-                let s = TinyStr4::from_bytes(v).map_err(|_| ParserError::InvalidSubtag)?;
+                let s = TinyStr4::from_bytes(v).map_err(|_| ParserError::InvalidRegion)?;
                 if !s.is_ascii_alphabetic() {
-                    return Err(ParserError::InvalidSubtag);
+                    return Err(ParserError::InvalidRegion);
                 }
                 Ok(Self(s.to_ascii_uppercase()))
             }
             3 => {
-                let s = TinyStr4::from_bytes(v).map_err(|_| ParserError::InvalidSubtag)?;
+                let s = TinyStr4::from_bytes(v).map_err(|_| ParserError::InvalidRegion)?;
                 if !s.is_ascii_numeric() {
-                    return Err(ParserError::InvalidSubtag);
+                    return Err(ParserError::InvalidRegion);
                 }
                 Ok(Self(s))
             }
-            _ => Err(ParserError::InvalidSubtag),
+            _ => Err(ParserError::InvalidRegion),
         }
     }
 
@@ -32,6 +64,74 @@ impl Region {
         self.0.as_str()
     }
 
+    /// Returns an iterator over the regions directly contained in this region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    ///
+    /// let region: Region = "021".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(region.children().map(|r| r.to_string()).collect::<Vec<_>>(),
+    ///            &["BM", "CA", "GL", "PM", "US"]);
+    /// ```
+    pub fn children(&self) -> impl Iterator<Item = Region> {
+        let children: &[u32] =
+            match REGION_CONTAINMENT.binary_search_by_key(&self.as_u32(), |(region, _)| *region) {
+                Ok(idx) => REGION_CONTAINMENT[idx].1,
+                Err(_) => &[],
+            };
+        children
+            .iter()
+            .map(|region| unsafe { Region::from_raw_unchecked(*region) })
+    }
+
+    /// Returns `true` if this region is directly or transitively contained
+    /// in the `other` region.
+    ///
+    /// A region is not contained in itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    ///
+    /// let fr: Region = "FR".parse().expect("Parsing failed.");
+    /// let europe: Region = "150".parse().expect("Parsing failed.");
+    /// let world: Region = "001".parse().expect("Parsing failed.");
+    /// let asia: Region = "142".parse().expect("Parsing failed.");
+    ///
+    /// assert_eq!(fr.contained_in(&europe), true);
+    /// assert_eq!(fr.contained_in(&world), true);
+    /// assert_eq!(fr.contained_in(&asia), false);
+    /// ```
+    pub fn contained_in(&self, other: &Region) -> bool {
+        other
+            .children()
+            .any(|child| child == *self || self.contained_in(&child))
+    }
+
+    /// Returns the packed integer representation of the subtag.
+    ///
+    /// The value is the little-endian integer of the canonically cased ASCII bytes, padded
+    /// with zero bytes, and is stable across releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    ///
+    /// let region: Region = "419".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(region.as_u32(), u32::from_le_bytes(*b"419\0"));
+    /// ```
+    pub fn as_u32(self) -> u32 {
+        self.into()
+    }
+
     /// # Safety
     ///
     /// This function accepts any u64 that is exected to be a valid
@@ -61,8 +161,8 @@ impl FromStr for Region {
     }
 }
 
-impl std::fmt::Display for Region {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Region {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.write_str(&self.0)
     }
 }
@@ -1,11 +1,47 @@
 use crate::parser::errors::ParserError;
-use std::str::FromStr;
+#[cfg(feature = "direction")]
+use crate::script_metadata_table::SCRIPT_METADATA;
+#[cfg(feature = "direction")]
+use crate::subtags::{Language, Region};
+#[cfg(feature = "direction")]
+use crate::CharacterDirection;
+use core::str::FromStr;
 use tinystr::TinyStr4;
 
+/// A script subtag (examples: `"Latn"`, `"Arab"`).
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::Script;
+///
+/// let script: Script = "cyrl".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(script, "Cyrl");
+/// assert_eq!(script.as_str(), "Cyrl");
+/// ```
 #[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
 pub struct Script(TinyStr4);
 
 impl Script {
+    /// Parses a bare script subtag, without a full language identifier.
+    ///
+    /// Four letter ISO 15924 codes are accepted and normalized to title
+    /// case. This is the same as parsing with `str::parse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Script;
+    ///
+    /// let script = Script::from_bytes(b"CYRL")
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(script, "Cyrl");
+    /// assert_eq!(script, "cyrl".parse::<Script>().expect("Parsing failed."));
+    /// assert!(Script::from_bytes(b"Cyr1").is_err());
+    /// ```
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
         let slen = v.len();
 
@@ -20,6 +56,25 @@ impl Script {
         self.0.as_str()
     }
 
+    /// Returns the packed integer representation of the subtag.
+    ///
+    /// The value is the little-endian integer of the canonically cased ASCII bytes, padded
+    /// with zero bytes, and is stable across releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Script;
+    ///
+    /// let script: Script = "latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(script.as_u32(), u32::from_le_bytes(*b"Latn"));
+    /// ```
+    pub fn as_u32(self) -> u32 {
+        self.into()
+    }
+
     /// # Safety
     ///
     /// This function accepts any u64 that is exected to be a valid
@@ -27,6 +82,89 @@ impl Script {
     pub const unsafe fn from_raw_unchecked(v: u32) -> Self {
         Self(TinyStr4::new_unchecked(v))
     }
+
+    /// Returns the CLDR `scriptMetadata` of the script, or `None` if CLDR
+    /// has no data for it.
+    ///
+    /// The table is produced by the `generate_script_metadata` binary from
+    /// `data/scriptMetadata.json`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Script;
+    /// use unic_langid_impl::CharacterDirection;
+    ///
+    /// let script: Script = "Hebr".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let metadata = script.metadata()
+    ///     .expect("Hebr has metadata.");
+    /// assert_eq!(metadata.direction, CharacterDirection::RTL);
+    /// assert_eq!(metadata.sample_char, '\u{5D0}');
+    ///
+    /// let script: Script = "Latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let metadata = script.metadata()
+    ///     .expect("Latn has metadata.");
+    /// assert_eq!(metadata.direction, CharacterDirection::LTR);
+    /// assert!(metadata.has_case);
+    /// ```
+    #[cfg(feature = "direction")]
+    pub fn metadata(self) -> Option<ScriptMetadata> {
+        let key: u32 = self.into();
+        let idx = SCRIPT_METADATA
+            .binary_search_by_key(&key, |(script, ..)| *script)
+            .ok()?;
+        let (
+            _,
+            rank,
+            sample_char,
+            rtl,
+            shaping_required,
+            has_case,
+            origin_country,
+            likely_language,
+        ) = SCRIPT_METADATA[idx];
+        Some(ScriptMetadata {
+            rank,
+            sample_char,
+            direction: if rtl {
+                CharacterDirection::RTL
+            } else {
+                CharacterDirection::LTR
+            },
+            shaping_required,
+            has_case,
+            origin_country: origin_country.map(|r| unsafe { Region::from_raw_unchecked(r) }),
+            likely_language: likely_language.map(|l| unsafe { Language::from_raw_unchecked(l) }),
+        })
+    }
+}
+
+/// Properties of a script from CLDR `scriptMetadata`, as returned by
+/// `Script::metadata`.
+///
+/// Fields wrapped in `Option` are `None` when the data the table was
+/// generated from does not provide them.
+#[cfg(feature = "direction")]
+#[derive(Debug, PartialEq)]
+pub struct ScriptMetadata {
+    /// The rank of the script by usage, where `1` is the most used.
+    pub rank: Option<u32>,
+    /// A representative character of the script.
+    pub sample_char: char,
+    /// The direction in which the script is written.
+    pub direction: CharacterDirection,
+    /// Whether rendering the script requires contextual shaping.
+    pub shaping_required: Option<bool>,
+    /// Whether the script distinguishes between upper and lower case.
+    pub has_case: bool,
+    /// The region where the script originated, if known.
+    pub origin_country: Option<Region>,
+    /// The language most likely to be written in the script, if known.
+    pub likely_language: Option<Language>,
 }
 
 impl From<Script> for u32 {
@@ -49,8 +187,8 @@ impl FromStr for Script {
     }
 }
 
-impl std::fmt::Display for Script {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Script {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.write_str(&self.0)
     }
 }
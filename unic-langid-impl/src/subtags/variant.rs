@@ -1,7 +1,20 @@
 use crate::parser::errors::ParserError;
-use std::str::FromStr;
+use core::str::FromStr;
 use tinystr::TinyStr8;
 
+/// A variant subtag (examples: `"macos"`, `"posix"`, `"1996"`).
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::Variant;
+///
+/// let variant: Variant = "POSIX".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(variant, "posix");
+/// assert_eq!(variant.as_str(), "posix");
+/// ```
 #[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
 pub struct Variant(TinyStr8);
 
@@ -9,7 +22,7 @@ impl Variant {
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
         let slen = v.len();
 
-        if slen < 4 || slen > 8 {
+        if !(4..=8).contains(&slen) {
             return Err(ParserError::InvalidSubtag);
         }
 
@@ -17,8 +30,8 @@ impl Variant {
 
         if (slen >= 5 && !s.is_ascii_alphanumeric())
             || (slen == 4
-                && !v[0].is_ascii_digit()
-                && v[1..].iter().any(|c: &u8| !c.is_ascii_alphanumeric()))
+                && (!v[0].is_ascii_digit()
+                    || v[1..].iter().any(|c: &u8| !c.is_ascii_alphanumeric())))
         {
             return Err(ParserError::InvalidSubtag);
         }
@@ -30,6 +43,25 @@ impl Variant {
         self.0.as_str()
     }
 
+    /// Returns the packed integer representation of the subtag.
+    ///
+    /// The value is the little-endian integer of the canonically cased ASCII bytes, padded
+    /// with zero bytes, and is stable across releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Variant;
+    ///
+    /// let variant: Variant = "macos".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(variant.as_u64(), u64::from_le_bytes(*b"macos\0\0\0"));
+    /// ```
+    pub fn as_u64(self) -> u64 {
+        self.into()
+    }
+
     /// # Safety
     ///
     /// This function accepts any u64 that is exected to be a valid
@@ -51,6 +83,12 @@ impl From<&Variant> for u64 {
     }
 }
 
+impl<'l> From<&'l Variant> for &'l str {
+    fn from(input: &'l Variant) -> Self {
+        input.0.as_str()
+    }
+}
+
 impl FromStr for Variant {
     type Err = ParserError;
 
@@ -59,8 +97,8 @@ impl FromStr for Variant {
     }
 }
 
-impl std::fmt::Display for Variant {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Variant {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.write_str(&self.0)
     }
 }
@@ -0,0 +1,43 @@
+use unic_langid_impl::parse_accept_language;
+
+#[test]
+fn test_parse_accept_language() {
+    let ranked = |header: &str| -> Vec<(String, f32)> {
+        parse_accept_language(header)
+            .into_iter()
+            .map(|(langid, q)| (langid.to_string(), q))
+            .collect()
+    };
+
+    assert_eq!(
+        ranked("da, en-gb;q=0.8, en;q=0.7"),
+        vec![
+            ("da".to_string(), 1.0),
+            ("en-GB".to_string(), 0.8),
+            ("en".to_string(), 0.7)
+        ]
+    );
+
+    // Sorted by quality, keeping the header order for ties.
+    assert_eq!(
+        ranked("en;q=0.5,de;Q=0.9 , pl , *;q=0.1, fr;q=0.5"),
+        vec![
+            ("pl".to_string(), 1.0),
+            ("de".to_string(), 0.9),
+            ("en".to_string(), 0.5),
+            ("fr".to_string(), 0.5),
+            ("und".to_string(), 0.1)
+        ]
+    );
+
+    // Malformed entries and unacceptable ones are skipped.
+    assert_eq!(
+        ranked("en-US-, de;q=1.5, fr;q=abc, it;q=0.1234, es;q, pt;q=0, ru;q=0.000, pl;q=1.000, ,"),
+        vec![("pl".to_string(), 1.0)]
+    );
+
+    // Other parameters are ignored.
+    assert_eq!(ranked("en;level=1;q=0.4"), vec![("en".to_string(), 0.4)]);
+
+    assert!(parse_accept_language("").is_empty());
+}
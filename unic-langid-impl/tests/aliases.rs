@@ -0,0 +1,86 @@
+use unic_langid_impl::LanguageIdentifier;
+
+#[test]
+fn test_canonicalize_aliases() {
+    let tests = &[
+        ("iw", "he", true),
+        ("in-ID", "id-ID", true),
+        ("ji", "yi", true),
+        ("mo-MD", "ro-MD", true),
+        ("tl-PH", "fil-PH", true),
+        ("sh", "sr-Latn", true),
+        ("sh-Cyrl", "sr-Cyrl", true),
+        ("prs", "fa-AF", true),
+        ("prs-IR", "fa-IR", true),
+        ("fre", "fr", true),
+        ("arb-EG", "ar-EG", true),
+        ("cnr", "sr-ME", true),
+        ("en-UK", "en-GB", true),
+        ("de-DD", "de-DE", true),
+        ("und-Qaai", "und-Zinh", true),
+        ("my-BU", "my-MM", true),
+        ("fr-ZR", "fr-CD", true),
+        ("sr-CS", "sr-RS", true),
+        ("sr-Latn-YU", "sr-Latn-RS", true),
+        ("ru-SU", "ru-RU", true),
+        ("cs-200", "cs-CZ", true),
+        ("und-062", "und-034", true),
+        ("en-840", "en-US", true),
+        ("en-US", "en-US", false),
+        ("und", "und", false),
+    ];
+
+    for (input, output, modified) in tests {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid.canonicalize_aliases(), *modified, "{}", input);
+        assert_eq!(&langid.to_string(), output);
+    }
+
+    // Split regions resolve to the likely region of the language if it is
+    // one of the successors, or the primary successor otherwise.
+    let split_tests = if cfg!(feature = "likelysubtags") {
+        &[
+            ("hy-SU", "hy-AM"),
+            ("sk-200", "sk-SK"),
+            ("nl-AN", "nl-CW"),
+            ("de-SU", "de-RU"),
+        ]
+    } else {
+        &[
+            ("hy-SU", "hy-RU"),
+            ("sk-200", "sk-CZ"),
+            ("nl-AN", "nl-CW"),
+            ("de-SU", "de-RU"),
+        ]
+    };
+    for (input, output) in split_tests {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        assert!(langid.canonicalize_aliases(), "{}", input);
+        assert_eq!(&langid.to_string(), output);
+    }
+
+    // Parsing alone never replaces aliases.
+    let langid: LanguageIdentifier = "iw".parse().unwrap();
+    assert_eq!(&langid.to_string(), "iw");
+}
+
+#[test]
+fn test_grandfathered_tags() {
+    let cases = [
+        ("i-klingon", "tlh"),
+        ("I_KLINGON", "tlh"),
+        ("zh-min-nan", "nan"),
+        ("art-lojban", "jbo"),
+        ("zh-guoyu", "cmn"),
+        ("en-GB-oed", "en-GB-oxendict"),
+        ("no-bok", "nb"),
+        ("sgn-BE-FR", "sfb"),
+    ];
+    for (input, output) in &cases {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid, *output);
+    }
+
+    assert!("i-default".parse::<LanguageIdentifier>().is_err());
+    assert!("i-klingon-x".parse::<LanguageIdentifier>().is_err());
+}
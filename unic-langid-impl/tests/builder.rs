@@ -0,0 +1,41 @@
+use unic_langid_impl::parser::ParserError;
+use unic_langid_impl::LanguageIdentifier;
+
+#[test]
+fn test_builder() {
+    let langid = LanguageIdentifier::builder()
+        .language("EN")
+        .script("latn")
+        .region("us")
+        .variant("POSIX")
+        .variant("macos")
+        .build()
+        .unwrap();
+    assert_eq!(&langid.to_string(), "en-Latn-US-macos-posix");
+
+    let langid = LanguageIdentifier::builder().build().unwrap();
+    assert_eq!(&langid.to_string(), "und");
+
+    assert_eq!(
+        LanguageIdentifier::builder()
+            .language("e1")
+            .region("u")
+            .build(),
+        Err(ParserError::InvalidLanguage.into())
+    );
+    assert_eq!(
+        LanguageIdentifier::builder()
+            .language("en")
+            .region("u")
+            .build(),
+        Err(ParserError::InvalidRegion.into())
+    );
+    assert_eq!(
+        LanguageIdentifier::builder()
+            .language("de")
+            .variant("1996")
+            .variant("1996")
+            .build(),
+        Err(ParserError::DuplicateVariant("1996".parse().unwrap()).into())
+    );
+}
@@ -1,4 +1,5 @@
-use unic_langid_impl::canonicalize;
+use std::borrow::Cow;
+use unic_langid_impl::{canonicalize, canonicalize_cow, normalize_in_place, tags_equivalent};
 
 fn assert_canonicalize(input: &str, output: &str) {
     assert_eq!(&canonicalize(input).unwrap(), output);
@@ -10,4 +11,85 @@ fn test_canonicalize() {
     assert_canonicalize("eN-uS", "en-US");
     assert_canonicalize("ZH_hans_hK", "zh-Hans-HK");
     assert_canonicalize("en-scouse-fonipa", "en-fonipa-scouse");
+    assert_canonicalize("EN_us_POSIX", "en-US-posix");
+    assert_canonicalize("zh-hant-cn", "zh-Hant-CN");
+}
+
+#[test]
+fn test_canonicalize_errors() {
+    assert!(canonicalize("").is_err());
+    assert!(canonicalize("-").is_err());
+    assert!(canonicalize("en-US-").is_err());
+    assert!(canonicalize("e").is_err());
+    assert!(canonicalize("en-ÁÁÁÁ").is_err());
+    assert!(canonicalize("en-verylongsubtag").is_err());
+}
+
+#[test]
+fn test_canonicalize_cow() {
+    for input in &[
+        "pl",
+        "en-US",
+        "zh-Hans-HK",
+        "en-fonipa-scouse",
+        "und",
+        "es-419",
+    ] {
+        assert_eq!(canonicalize_cow(input), Ok(Cow::Borrowed(*input)));
+    }
+
+    for input in &[
+        "Pl",
+        "en_US",
+        "ZH-hans-hk",
+        "en-scouse-fonipa",
+        "i-klingon",
+        "UND",
+    ] {
+        let result = canonicalize_cow(input).unwrap();
+        assert!(matches!(result, Cow::Owned(_)), "{}", input);
+        assert_eq!(result, canonicalize(input).unwrap());
+    }
+
+    for input in &["", "-", "en-US-", "en--US", "en-posix-POSIX"] {
+        assert!(canonicalize_cow(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_normalize_in_place() {
+    for input in &[
+        "Pl",
+        "eN-uS",
+        "ZH_hans_hK",
+        "en-scouse-fonipa",
+        "und",
+        "i-klingon",
+    ] {
+        let mut s = input.to_string();
+        let ptr = s.as_ptr();
+        normalize_in_place(&mut s).unwrap();
+        assert_eq!(s, canonicalize(input).unwrap());
+        assert_eq!(s.as_ptr(), ptr, "{}", input);
+    }
+
+    let mut s = String::from("zh-guoyu");
+    normalize_in_place(&mut s).unwrap();
+    assert_eq!(s, "cmn");
+
+    for input in &["", "-", "en-US-", "en-verylongsubtag", "en-posix-POSIX"] {
+        let mut s = input.to_string();
+        assert!(normalize_in_place(&mut s).is_err());
+        assert_eq!(&s, input);
+    }
+}
+
+#[test]
+fn test_tags_equivalent() {
+    assert!(tags_equivalent("EN_us", "en-US"));
+    assert!(tags_equivalent("zh_hant_TW", "ZH-Hant-tw"));
+    assert!(tags_equivalent("", ""));
+    assert!(!tags_equivalent("en-US", "en-GB"));
+    assert!(!tags_equivalent("en-US", "en-US-posix"));
+    assert!(!tags_equivalent("en-US", "en.US"));
 }
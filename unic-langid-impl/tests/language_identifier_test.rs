@@ -1,7 +1,7 @@
-use unic_langid_impl::parser::parse_language_identifier;
+use unic_langid_impl::parser::{parse_language_identifier, ParserError};
 use unic_langid_impl::subtags;
-use unic_langid_impl::CharacterDirection;
-use unic_langid_impl::LanguageIdentifier;
+use unic_langid_impl::{CharacterDirection, CharacterDirectionError};
+use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
 
 fn assert_language_identifier(
     loc: &LanguageIdentifier,
@@ -77,6 +77,77 @@ fn test_sorted_variants() {
     assert_eq!(&langid.to_string(), "en-macos-nedis");
 }
 
+#[test]
+fn test_from_parts_roundtrip() {
+    let langid: LanguageIdentifier = "sr-Cyrl-RS-ekavsk".parse().unwrap();
+    let (lang, script, region, variants) = langid.clone().into_parts();
+    assert_eq!(lang.as_str(), "sr");
+    assert_eq!(script.as_ref().map(|s| s.as_str()), Some("Cyrl"));
+    assert_eq!(region.as_ref().map(|r| r.as_str()), Some("RS"));
+
+    let langid2 = LanguageIdentifier::from_parts(lang, script, region, &variants);
+    assert_eq!(langid, langid2);
+
+    let langid3 = LanguageIdentifier::from_parts(
+        "und".parse().unwrap(),
+        None,
+        Some("DE".parse().unwrap()),
+        &[],
+    );
+    assert_eq!(&langid3.to_string(), "und-DE");
+}
+
+#[test]
+fn test_raw_parts_roundtrip() {
+    for input in &[
+        "en",
+        "und",
+        "und-Latn",
+        "sr-Cyrl-RS-ekavsk",
+        "de-1996-1901",
+        "zh-Hant-TW",
+    ] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        let (lang, script, region, variants) = langid.clone().into_raw_parts();
+        let langid2 = LanguageIdentifier::from_raw_parts(lang, script, region, &variants).unwrap();
+        assert_eq!(langid, langid2);
+    }
+
+    let (lang, script, region, _) = "und"
+        .parse::<LanguageIdentifier>()
+        .unwrap()
+        .into_raw_parts();
+    assert_eq!((lang, script, region), (None, None, None));
+
+    // Casing is normalized.
+    let langid = LanguageIdentifier::from_raw_parts(
+        Some(u64::from_le_bytes(*b"EN\0\0\0\0\0\0")),
+        None,
+        Some(u32::from_le_bytes(*b"us\0\0")),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(&langid.to_string(), "en-US");
+
+    assert!(LanguageIdentifier::from_raw_parts(Some(0), None, None, &[]).is_err());
+    assert!(LanguageIdentifier::from_raw_parts(
+        Some(u64::from_le_bytes(*b"e\0n\0\0\0\0\0")),
+        None,
+        None,
+        &[]
+    )
+    .is_err());
+    assert!(LanguageIdentifier::from_raw_parts(
+        None,
+        Some(u32::from_le_bytes(*b"US\0\0")),
+        None,
+        &[]
+    )
+    .is_err());
+    let macos = u64::from_le_bytes(*b"macos\0\0\0");
+    assert!(LanguageIdentifier::from_raw_parts(None, None, None, &[macos, macos]).is_err());
+}
+
 #[test]
 fn test_from_parts_unchecked() {
     let langid: LanguageIdentifier = "en-nedis-macos".parse().unwrap();
@@ -145,6 +216,22 @@ fn test_matches_as_range() {
 }
 
 #[test]
+fn test_matches_range_direction() {
+    let langid_en: LanguageIdentifier = "en".parse().unwrap();
+    let langid_en_us: LanguageIdentifier = "en-US".parse().unwrap();
+
+    // `en` as a range covers `en-US`...
+    assert!(langid_en.matches(&langid_en_us, true, false));
+    // ...but `en-US` as a range does not cover `en`.
+    assert!(!langid_en_us.matches(&langid_en, true, false));
+    assert!(langid_en_us.matches(&langid_en, false, true));
+
+    let langid_fr: LanguageIdentifier = "fr".parse().unwrap();
+    assert!(!langid_en.matches(&langid_fr, true, true));
+}
+
+#[test]
+#[cfg(feature = "direction")]
 fn test_character_direction() {
     let langid: LanguageIdentifier = "en-US".parse().unwrap();
     let langid2: LanguageIdentifier = "ar-AF".parse().unwrap();
@@ -152,6 +239,145 @@ fn test_character_direction() {
     assert_eq!(langid2.character_direction(), CharacterDirection::RTL);
 }
 
+#[test]
+#[cfg(feature = "direction")]
+fn test_character_direction_script() {
+    let tests = &[
+        ("en-Arab", CharacterDirection::RTL),
+        ("az-Arab", CharacterDirection::RTL),
+        ("ku-Latn", CharacterDirection::LTR),
+        ("ff-Adlm", CharacterDirection::RTL),
+        ("rhg-Rohg", CharacterDirection::RTL),
+        ("ar-Latn", CharacterDirection::LTR),
+        ("he-Hebr-IL", CharacterDirection::RTL),
+        ("az", CharacterDirection::LTR),
+        ("ku", CharacterDirection::LTR),
+    ];
+
+    for (input, direction) in tests {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid.character_direction(), *direction, "{}", input);
+    }
+}
+
+#[test]
+fn test_character_direction_from_str() {
+    assert_eq!("ltr".parse(), Ok(CharacterDirection::LTR));
+    assert_eq!("Rtl".parse(), Ok(CharacterDirection::RTL));
+    assert_eq!(
+        "auto".parse::<CharacterDirection>(),
+        Err(CharacterDirectionError::InvalidDirection)
+    );
+    assert!("".parse::<CharacterDirection>().is_err());
+
+    for dir in &[CharacterDirection::LTR, CharacterDirection::RTL] {
+        assert_eq!(
+            dir.to_string().parse::<CharacterDirection>().as_ref(),
+            Ok(dir)
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "direction")]
+fn test_script_direction() {
+    let tests = &[
+        ("Arab", CharacterDirection::RTL),
+        ("HEBR", CharacterDirection::RTL),
+        ("adlm", CharacterDirection::RTL),
+        ("Thaa", CharacterDirection::RTL),
+        ("Syrc", CharacterDirection::RTL),
+        ("Yezi", CharacterDirection::RTL),
+        ("Latn", CharacterDirection::LTR),
+        ("Cyrl", CharacterDirection::LTR),
+        ("Zzzz", CharacterDirection::LTR),
+        ("", CharacterDirection::LTR),
+        ("Ara", CharacterDirection::LTR),
+        ("ar", CharacterDirection::LTR),
+    ];
+
+    for (input, direction) in tests {
+        assert_eq!(
+            unic_langid_impl::script_direction(input),
+            *direction,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "direction")]
+fn test_character_direction_for_language() {
+    let tests = &[
+        ("ar", CharacterDirection::RTL),
+        ("HE", CharacterDirection::RTL),
+        ("ckb", CharacterDirection::RTL),
+        ("en", CharacterDirection::LTR),
+        ("und", CharacterDirection::LTR),
+        ("", CharacterDirection::LTR),
+        ("ar-EG", CharacterDirection::LTR),
+    ];
+
+    for (input, direction) in tests {
+        assert_eq!(
+            unic_langid_impl::character_direction_for_language(input),
+            *direction,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "direction")]
+fn test_rtl_languages() {
+    let langs = unic_langid_impl::rtl_languages();
+    assert!(!langs.is_empty());
+    assert!(langs.windows(2).all(|w| w[0] < w[1]));
+
+    for lang in langs {
+        assert_eq!(
+            unic_langid_impl::character_direction_for_language(lang),
+            CharacterDirection::RTL,
+            "{}",
+            lang
+        );
+    }
+}
+
+#[test]
+fn test_langid_ord_matches_string_ord() {
+    let input = &[
+        "und-US",
+        "en-Latn-US",
+        "zh-Hant-TW",
+        "en-US-posix",
+        "en-419",
+        "en-Latn",
+        "und",
+        "en-US",
+        "sr-Cyrl",
+        "en",
+        "az-Arab-IR",
+        "sr-Latn-ME",
+        "enm",
+        "en-GB",
+    ];
+
+    let mut langids = input
+        .iter()
+        .map(|l| -> LanguageIdentifier { l.parse().unwrap() })
+        .collect::<Vec<_>>();
+    langids.sort();
+
+    let mut strings = input.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    strings.sort();
+
+    let result = langids.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    assert_eq!(result, strings);
+}
+
 #[test]
 fn test_langid_ord() {
     let input = &[
@@ -190,3 +416,759 @@ fn test_langid_ord() {
         ]
     );
 }
+
+#[test]
+fn test_is_empty() {
+    let langid = LanguageIdentifier::default();
+    assert!(langid.is_empty());
+
+    let langid: LanguageIdentifier = "und".parse().unwrap();
+    assert!(langid.is_empty());
+
+    let langid: LanguageIdentifier = "und-Latn".parse().unwrap();
+    assert!(!langid.is_empty());
+
+    let langid: LanguageIdentifier = "und-macos".parse().unwrap();
+    assert!(!langid.is_empty());
+
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    assert!(!langid.is_empty());
+}
+
+#[test]
+fn test_is_language_only() {
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    assert!(langid.is_language_only());
+
+    let langid: LanguageIdentifier = "und".parse().unwrap();
+    assert!(langid.is_language_only());
+
+    for input in &[
+        "en-US",
+        "en-Latn",
+        "en-macos",
+        "und-US",
+        "und-Latn",
+        "und-macos",
+    ] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert!(!langid.is_language_only(), "{}", input);
+    }
+}
+
+#[test]
+fn test_try_set_variants() {
+    let mut langid: LanguageIdentifier = "de-DE".parse().unwrap();
+
+    langid.try_set_variants(&["nedis", "MACOS"]).unwrap();
+    assert_eq!(&langid.to_string(), "de-DE-macos-nedis");
+
+    assert_eq!(
+        langid.try_set_variants(&["1996", "macos", "1996"]),
+        Err(ParserError::DuplicateVariant("1996".parse().unwrap()).into())
+    );
+    assert_eq!(
+        langid.try_set_variants(&["1996", "abc"]),
+        Err(ParserError::InvalidSubtag.into())
+    );
+    assert_eq!(&langid.to_string(), "de-DE-macos-nedis");
+
+    langid.try_set_variants(&[]).unwrap();
+    assert_eq!(&langid.to_string(), "de-DE");
+}
+
+#[test]
+fn test_subtag_newtypes() {
+    let language: subtags::Language = "PL".parse().unwrap();
+    assert_eq!(language, "pl");
+    assert_eq!(language.to_string(), "pl");
+    assert!("p1".parse::<subtags::Language>().is_err());
+
+    let script: subtags::Script = "latn".parse().unwrap();
+    assert_eq!(script, "Latn");
+    assert_eq!(script.to_string(), "Latn");
+    assert!("Lat".parse::<subtags::Script>().is_err());
+
+    let region: subtags::Region = "419".parse().unwrap();
+    assert_eq!(region, "419");
+    assert_eq!(region.to_string(), "419");
+    assert!("4a9".parse::<subtags::Region>().is_err());
+
+    let variant: subtags::Variant = "Valencia".parse().unwrap();
+    assert_eq!(variant, "valencia");
+    assert_eq!(variant.to_string(), "valencia");
+    assert!("abc".parse::<subtags::Variant>().is_err());
+
+    let langid = LanguageIdentifier::from_parts(language, Some(script), Some(region), &[variant]);
+    assert_eq!(langid.language, language);
+    assert_eq!(langid.script, Some(script));
+    assert_eq!(langid.region, Some(region));
+    assert!(langid.has_variant(variant));
+}
+
+#[test]
+fn test_bare_region_and_script() {
+    let region = subtags::Region::from_bytes(b"us").unwrap();
+    assert_eq!(region, "US".parse::<subtags::Region>().unwrap());
+    assert_eq!(region.as_str(), "US");
+    assert_eq!(format!("{}", region), "US");
+
+    let script = subtags::Script::from_bytes(b"HANS").unwrap();
+    assert_eq!(script, "hans".parse::<subtags::Script>().unwrap());
+    assert_eq!(script.as_str(), "Hans");
+    assert_eq!(format!("{}", script), "Hans");
+
+    let langid: LanguageIdentifier = "zh-Hans-US".parse().unwrap();
+    assert_eq!(langid.region, Some(region));
+    assert_eq!(langid.script, Some(script));
+
+    for input in &["", "U", "USA", "U1", "1234"] {
+        assert!(
+            subtags::Region::from_bytes(input.as_bytes()).is_err(),
+            "{}",
+            input
+        );
+    }
+    for input in &["", "Han", "Hans1", "Han5", "zh-Hans"] {
+        assert!(
+            subtags::Script::from_bytes(input.as_bytes()).is_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_subtag_integers() {
+    let langid: LanguageIdentifier = "sr-Cyrl-RS-ekavsk".parse().unwrap();
+    let (lang, script, region, variants) = langid.clone().into_raw_parts();
+
+    assert_eq!(langid.language.as_u64(), lang);
+    assert_eq!(langid.script.map(|s| s.as_u32()), script);
+    assert_eq!(langid.region.map(|r| r.as_u32()), region);
+    assert_eq!(
+        langid.variants().map(|v| v.as_u64()).collect::<Vec<_>>(),
+        variants
+    );
+
+    let lower: subtags::Language = "sr".parse().unwrap();
+    let upper: subtags::Language = "SR".parse().unwrap();
+    assert_eq!(lower.as_u64(), upper.as_u64());
+    assert_ne!(
+        lower.as_u64(),
+        "sq".parse::<subtags::Language>().unwrap().as_u64()
+    );
+}
+
+#[test]
+fn test_to_string_with_separator() {
+    let tests = &[
+        ("en", "en"),
+        ("en-US", "en_US"),
+        ("sr-Cyrl-RS", "sr_Cyrl_RS"),
+        ("de-DE-1996-1901", "de_DE_1901_1996"),
+        ("und-419", "und_419"),
+    ];
+
+    for (input, output) in tests {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(&langid.to_string_with_separator('_'), output);
+    }
+}
+
+#[test]
+fn test_str_eq() {
+    let langid: LanguageIdentifier = "en-US".parse().unwrap();
+    assert_eq!(langid, "en-US");
+    assert_eq!(langid, *"en-US");
+    assert_eq!(langid, "EN-us");
+    assert_eq!(langid, "en_US");
+    assert_ne!(langid, "en");
+    assert_ne!(langid, "en-Latn-US");
+    assert_ne!(langid, "en-US-posix");
+    assert_ne!(langid, "en-GB");
+    assert_ne!(langid, "en-US-");
+    assert_ne!(langid, "");
+
+    let langid: LanguageIdentifier = "sr-Cyrl-RS-macos-posix".parse().unwrap();
+    assert_eq!(langid, "sr-Cyrl-RS-macos-posix");
+    assert_eq!(langid, "SR-cyrl-rs-MACOS-posix");
+    assert_ne!(langid, "sr-Cyrl-RS-posix-macos");
+    assert_ne!(langid, "sr-Cyrl-RS-macos");
+    assert_ne!(langid, "sr-Cyrl-RS-macos-macos");
+    assert_ne!(langid, "sr-Cyrl-RS-macos-posix-1996");
+
+    let langid = LanguageIdentifier::default();
+    assert_eq!(langid, "und");
+    assert_eq!(langid, "UND");
+    assert_ne!(langid, "");
+
+    for input in &["art-lojban", "I_KLINGON", "zh-min-nan", "en-GB-oed"] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid, *input);
+    }
+    let langid: LanguageIdentifier = "art".parse().unwrap();
+    assert_ne!(langid, "art-lojban");
+}
+
+#[test]
+#[cfg(feature = "direction")]
+fn test_cldr_version() {
+    assert_eq!(unic_langid_impl::CLDR_VERSION, "37");
+}
+
+#[test]
+fn test_malformed_input_errors() {
+    let inputs: &[&[u8]] = &[
+        b"",
+        b"-",
+        b"en-",
+        b"en--US",
+        b"toolonglanguage",
+        b"en-toolongsubtag",
+        b"en-US-\xff\xfe",
+        b"\xc3\xa9n",
+        b"en-\0",
+        b"en-Latn-US-macos-Latn",
+        b"en-macos-abcd",
+    ];
+
+    for input in inputs {
+        assert!(
+            LanguageIdentifier::from_bytes(input).is_err(),
+            "{:?}",
+            String::from_utf8_lossy(input)
+        );
+    }
+
+    assert_eq!(
+        LanguageIdentifier::from_bytes(b"en-toolongsubtag"),
+        Err(ParserError::InvalidSubtag.into())
+    );
+}
+
+#[test]
+fn test_parse_all() {
+    let results = LanguageIdentifier::parse_all(vec!["en-US", "und", "en-US-", "sr_cyrl_rs"]);
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0], Ok("en-US".parse().unwrap()));
+    assert_eq!(results[1], Ok(LanguageIdentifier::default()));
+    assert_eq!(
+        results[2],
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidSubtag
+        ))
+    );
+    assert_eq!(results[3], Ok("sr-Cyrl-RS".parse().unwrap()));
+
+    assert!(LanguageIdentifier::parse_all(vec![]).is_empty());
+}
+
+#[test]
+fn test_und_roundtrip() {
+    for input in &[
+        "und",
+        "und-US",
+        "und-Latn-US",
+        "und-macos",
+        "und-Latn-macos",
+    ] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert!(langid.language.is_empty());
+        assert_eq!(&langid.to_string(), input);
+    }
+
+    let langid: LanguageIdentifier = "UND_us".parse().unwrap();
+    assert_eq!(&langid.to_string(), "und-US");
+}
+
+#[test]
+fn test_includes() {
+    let tests = &[
+        ("en-US", "en", true),
+        ("en-Latn-US", "en-US", true),
+        ("en-Latn-US", "en-Latn", true),
+        ("en-US", "und-US", true),
+        ("en-US", "und", true),
+        ("de-DE-1996-macos", "de-1996", true),
+        ("en", "en", true),
+        ("en", "en-US", false),
+        ("en-US", "en-Latn-US", false),
+        ("en-US", "en-GB", false),
+        ("und-US", "en-US", false),
+        ("de-DE", "de-DE-1996", false),
+    ];
+
+    for (input1, input2, expected) in tests {
+        let langid1: LanguageIdentifier = input1.parse().unwrap();
+        let langid2: LanguageIdentifier = input2.parse().unwrap();
+        assert_eq!(
+            langid1.includes(&langid2),
+            *expected,
+            "{} {}",
+            input1,
+            input2
+        );
+    }
+}
+
+#[test]
+fn test_fallback_chain() {
+    let tests: &[(&str, &[&str])] = &[
+        ("en-Latn-US", &["en-Latn-US", "en-Latn", "en"]),
+        ("en-US", &["en-US", "en"]),
+        ("de-DE-1996-macos", &["de-DE-1996-macos", "de-DE", "de"]),
+        ("sr-Cyrl", &["sr-Cyrl", "sr"]),
+        ("en", &["en"]),
+        ("und", &["und"]),
+        ("und-US", &["und-US", "und"]),
+    ];
+
+    for (input, expected) in tests {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        let chain: Vec<String> = langid.fallback_chain().map(|l| l.to_string()).collect();
+        assert_eq!(chain, *expected);
+    }
+}
+
+#[test]
+fn test_try_from() {
+    use std::convert::TryFrom;
+
+    let langid = LanguageIdentifier::try_from("en_us").unwrap();
+    assert_eq!(&langid.to_string(), "en-US");
+
+    let langid = LanguageIdentifier::try_from(String::from("sr-cyrl")).unwrap();
+    assert_eq!(&langid.to_string(), "sr-Cyrl");
+
+    assert_eq!(
+        LanguageIdentifier::try_from("en-US-"),
+        "en-US-".parse::<LanguageIdentifier>()
+    );
+    assert!(LanguageIdentifier::try_from(String::from("x")).is_err());
+}
+
+#[test]
+fn test_set_region() {
+    let mut langid: LanguageIdentifier = "es".parse().unwrap();
+
+    for (input, output) in &[("419", "es-419"), ("150", "es-150"), ("ar", "es-AR")] {
+        langid.set_region(input).unwrap();
+        assert_eq!(&langid.to_string(), output);
+    }
+
+    for input in &["12", "ABCD", "A", "4A9", "1234", ""] {
+        assert_eq!(
+            langid.set_region(input),
+            Err(ParserError::InvalidRegion.into()),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(&langid.to_string(), "es-AR");
+
+    let langid: LanguageIdentifier = "es-419".parse().unwrap();
+    assert_eq!(langid.region, Some("419".parse().unwrap()));
+    assert_eq!(&langid.to_string(), "es-419");
+}
+
+#[test]
+fn test_display_long_identifier() {
+    let input = "en-Latn-US-1abc-2abc-3abc-4abc-5abc-6abc-7abc-8abc-9abc-10abc-11abc-12abc-13abc";
+    let langid: LanguageIdentifier = input.parse().unwrap();
+    assert!(input.len() > 64);
+    assert_eq!(
+        langid.to_string(),
+        "en-Latn-US-10abc-11abc-12abc-13abc-1abc-2abc-3abc-4abc-5abc-6abc-7abc-8abc-9abc"
+    );
+    assert_eq!(format!("{}", langid), langid.to_string());
+}
+
+#[test]
+fn test_display_alternate() {
+    let tests = &[
+        ("en-us", "en-US", "en_US"),
+        ("EN_LATN_us_POSIX", "en-Latn-US-posix", "en_Latn_US_posix"),
+        ("und", "und", "und"),
+        ("es-419", "es-419", "es_419"),
+    ];
+    for (input, canonical, alternate) in tests {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(&format!("{}", langid), canonical);
+        assert_eq!(&format!("{:#}", langid), alternate);
+        assert_eq!(
+            &format!("{:#}", langid),
+            &langid.to_string_with_separator('_')
+        );
+    }
+
+    let input = "en-Latn-US-1abc-2abc-3abc-4abc-5abc-6abc-7abc-8abc-9abc-10abc-11abc-12abc-13abc";
+    let langid: LanguageIdentifier = input.parse().unwrap();
+    assert_eq!(
+        format!("{:#}", langid),
+        langid.to_string_with_separator('_')
+    );
+}
+
+#[test]
+fn test_without_subtags() {
+    let langid: LanguageIdentifier = "sr-Latn-RS-ekavsk-1996".parse().unwrap();
+
+    assert_eq!(langid.without_script(), "sr-RS-1996-ekavsk");
+    assert_eq!(langid.without_region(), "sr-Latn-1996-ekavsk");
+    assert_eq!(langid.without_variants(), "sr-Latn-RS");
+    assert_eq!(langid, "sr-Latn-RS-1996-ekavsk");
+
+    let candidates: Vec<_> = std::iter::once(langid.clone())
+        .chain(std::iter::once(langid.without_variants()))
+        .chain(std::iter::once(langid.without_variants().without_region()))
+        .map(|l| l.to_string())
+        .collect();
+    assert_eq!(
+        candidates,
+        &["sr-Latn-RS-1996-ekavsk", "sr-Latn-RS", "sr-Latn"]
+    );
+
+    let und = LanguageIdentifier::default();
+    assert_eq!(und.without_script(), und);
+    assert_eq!(und.without_region(), und);
+    assert_eq!(und.without_variants(), und);
+}
+
+#[test]
+fn test_with_subtags() {
+    let langid: LanguageIdentifier = "en-US".parse().unwrap();
+
+    let langid = langid
+        .with_language("pt")
+        .and_then(|l| l.with_script("latn"))
+        .and_then(|l| l.with_region("br"))
+        .and_then(|l| l.with_variants(&["ao1990", "abl1943"]))
+        .unwrap();
+    assert_eq!(&langid.to_string(), "pt-Latn-BR-abl1943-ao1990");
+
+    let langid = langid.with_variants::<&str>(&[]).unwrap();
+    assert_eq!(&langid.to_string(), "pt-Latn-BR");
+
+    let langid: LanguageIdentifier = "en-US".parse().unwrap();
+    assert_eq!(
+        langid.clone().with_language("e1"),
+        Err(ParserError::InvalidLanguage.into())
+    );
+    assert_eq!(
+        langid.clone().with_region("GBR"),
+        Err(ParserError::InvalidRegion.into())
+    );
+    assert!(langid.clone().with_script("La").is_err());
+    assert_eq!(
+        langid.with_variants(&["macos", "MACOS"]),
+        Err(ParserError::DuplicateVariant("macos".parse().unwrap()).into())
+    );
+}
+
+#[test]
+fn test_parse_duplicate_variants() {
+    let tests = &[
+        ("de-1996-1996", "1996"),
+        ("pl-macos-Windows-nedis-macos", "macos"),
+        ("en-US-POSIX-posix", "posix"),
+    ];
+
+    for (input, variant) in tests {
+        assert_eq!(
+            input.parse::<LanguageIdentifier>(),
+            Err(ParserError::DuplicateVariant(variant.parse().unwrap()).into()),
+            "{}",
+            input
+        );
+    }
+
+    let err = "de-1996-1996".parse::<LanguageIdentifier>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parser error: Duplicate variant subtag: 1996"
+    );
+}
+
+#[test]
+fn test_sort_key() {
+    let mut list: Vec<LanguageIdentifier> = [
+        "pl",
+        "en-Latn",
+        "es-AR",
+        "en-US",
+        "es-419",
+        "es-013",
+        "en",
+        "en-US-posix",
+        "und",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    list.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    assert_eq!(
+        list,
+        vec![
+            "und",
+            "en",
+            "en-US",
+            "en-US-posix",
+            "en-Latn",
+            "es-013",
+            "es-419",
+            "es-AR",
+            "pl"
+        ]
+    );
+
+    let li1: LanguageIdentifier = "EN_us".parse().unwrap();
+    let li2: LanguageIdentifier = "en-US".parse().unwrap();
+    assert!(li1.sort_key() == li2.sort_key());
+}
+
+#[test]
+fn test_parse_lenient() {
+    let inputs = [
+        ("en", "en"),
+        ("en-", "en"),
+        ("en_", "en"),
+        ("en-US-", "en-US"),
+        ("en-Latn-US-macos--", "en-Latn-US-macos"),
+        ("en-US-_", "en-US"),
+    ];
+    for (input, expected) in &inputs {
+        let langid = LanguageIdentifier::parse_lenient(input).unwrap();
+        assert_eq!(langid.to_string(), *expected);
+    }
+
+    for input in &["", "-", "-en", "en--US", "en-US-x-"] {
+        assert!(
+            LanguageIdentifier::parse_lenient(input).is_err(),
+            "{}",
+            input
+        );
+    }
+    assert!("en-".parse::<LanguageIdentifier>().is_err());
+}
+
+#[test]
+fn test_is_well_formed() {
+    let inputs = [
+        "",
+        "und",
+        "en",
+        "EN_us",
+        "en-US-",
+        "-en",
+        "en--US",
+        "qaa-Qaaa-QM-x",
+        "sr-Latn-RS",
+        "zh-Hant-TW-1994-posix",
+        "en-posix-POSIX",
+        "en-1994-posix-1994",
+        "en-Latn-Latn",
+        "en-US-Latn",
+        "en-419",
+        "en-4190",
+        "en-12345",
+        "pl-macos-Windows-nedis-aRabic",
+        "i-klingon",
+        "I-KLINGON",
+        "i-default",
+        "zh-min-nan",
+        "en-ÁÁ",
+        "abcdefghi",
+        "en-u-ca",
+        "en-a",
+        "toolongvariant",
+        "de-DE-1996",
+        "de-DE-199",
+        "x-private",
+    ];
+    for input in &inputs {
+        assert_eq!(
+            LanguageIdentifier::is_well_formed(input),
+            input.parse::<LanguageIdentifier>().is_ok(),
+            "{}",
+            input
+        );
+    }
+
+    assert!(LanguageIdentifier::is_well_formed("qaa-Qaaa-QM"));
+    assert!(!LanguageIdentifier::is_well_formed("en-posix-POSIX"));
+}
+
+#[test]
+fn test_validate() {
+    for input in &["und", "en-US", "en-macos", "de-1996-1901-posix"] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid.validate(), Ok(()));
+    }
+
+    let macos: subtags::Variant = "macos".parse().unwrap();
+    let posix: subtags::Variant = "posix".parse().unwrap();
+    let unchecked = |variants: Vec<subtags::Variant>| {
+        LanguageIdentifier::from_raw_parts_unchecked(
+            "en".parse().unwrap(),
+            None,
+            None,
+            Some(variants.into_boxed_slice()),
+        )
+    };
+
+    assert_eq!(unchecked(vec![macos, posix]).validate(), Ok(()));
+    assert_eq!(
+        unchecked(vec![posix, macos]).validate(),
+        Err(LanguageIdentifierError::UnsortedVariants)
+    );
+    assert_eq!(
+        unchecked(vec![macos, macos]).validate(),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::DuplicateVariant(macos)
+        ))
+    );
+}
+
+#[test]
+fn test_total_cmp() {
+    use std::cmp::Ordering;
+
+    let parse = |s: &str| -> LanguageIdentifier { s.parse().unwrap() };
+
+    let tests = &[
+        ("en", "en", Ordering::Equal),
+        ("EN_us", "en-US", Ordering::Equal),
+        ("und", "en", Ordering::Less),
+        ("en", "en-Latn", Ordering::Less),
+        ("en-Latn", "en-US", Ordering::Greater),
+        ("en-US", "en-US-posix", Ordering::Less),
+        ("ab", "ba", Ordering::Greater),
+        ("de-1996", "de-1901", Ordering::Greater),
+    ];
+    for (a, b, ordering) in tests {
+        assert_eq!(parse(a).total_cmp(&parse(b)), *ordering, "{} {}", a, b);
+        assert_eq!(
+            parse(b).total_cmp(&parse(a)),
+            ordering.reverse(),
+            "{} {}",
+            a,
+            b
+        );
+    }
+
+    let mut list: Vec<LanguageIdentifier> = ["sr-Cyrl", "ba", "en-US", "und", "ab", "en"]
+        .iter()
+        .map(|s| parse(s))
+        .collect();
+    list.sort_by(LanguageIdentifier::total_cmp);
+    assert_eq!(list, vec!["und", "ba", "ab", "en", "en-US", "sr-Cyrl"]);
+}
+
+#[test]
+fn test_to_icu_locale_id() {
+    let tests = &[
+        ("en", "en"),
+        ("en-US", "en_US"),
+        ("zh-hant-tw", "zh_Hant_TW"),
+        ("es-419", "es_419"),
+        ("de-DE-1996", "de_DE_1996"),
+        ("sl-rozaj-biske", "sl__BISKE_ROZAJ"),
+        ("sr-Latn-ekavsk", "sr_Latn__EKAVSK"),
+        ("und", ""),
+        ("und-US", "_US"),
+        ("und-Latn", "_Latn"),
+    ];
+    for (input, output) in tests {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(&langid.to_icu_locale_id(), output);
+    }
+}
+
+#[test]
+fn test_add_variant() {
+    let mut langid: LanguageIdentifier = "de-DE".parse().unwrap();
+    assert_eq!(langid.add_variant("macos"), Ok(true));
+    assert_eq!(langid.add_variant("1996"), Ok(true));
+    assert_eq!(langid.add_variant("POSIX"), Ok(true));
+    assert_eq!(langid.add_variant("MacOS"), Ok(false));
+    assert_eq!(langid.to_string(), "de-DE-1996-macos-posix");
+    assert_eq!(
+        langid,
+        "de-DE-posix-macos-1996"
+            .parse::<LanguageIdentifier>()
+            .unwrap()
+    );
+
+    assert_eq!(
+        langid.add_variant("mac"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidSubtag
+        ))
+    );
+    assert_eq!(langid.variant_count(), 3);
+}
+
+#[test]
+fn test_remove_variant() {
+    let mut langid: LanguageIdentifier = "de-DE-1996-macos-posix".parse().unwrap();
+    assert!(langid.remove_variant("MACOS"));
+    assert!(!langid.remove_variant("macos"));
+    assert!(!langid.remove_variant("mac"));
+    assert_eq!(langid.to_string(), "de-DE-1996-posix");
+
+    assert!(langid.remove_variant("1996"));
+    assert!(langid.remove_variant("posix"));
+    assert_eq!(langid.variant_count(), 0);
+    assert_eq!(langid, "de-DE".parse::<LanguageIdentifier>().unwrap());
+    assert!(!langid.remove_variant("posix"));
+}
+
+#[test]
+fn test_from_bytes_non_ascii() {
+    let header = b"de-AT;q=0.9";
+    let langid = LanguageIdentifier::from_bytes(&header[..5]).unwrap();
+    assert_eq!(langid, "de-AT");
+
+    let inputs: &[&[u8]] = &[
+        b"\xff",
+        b"en-\xff\xfe",
+        b"en-US-\xc3\xa9\xc3\xa9\xc3\xa9\xc3\xa9",
+        b"\xc3\xa9n",
+        b"en\x00",
+        b"en-\x80\x80",
+    ];
+    for input in inputs {
+        assert!(
+            LanguageIdentifier::from_bytes(input).is_err(),
+            "{:?}",
+            input
+        );
+        assert!(!LanguageIdentifier::is_well_formed(
+            &String::from_utf8_lossy(input)
+        ));
+    }
+}
+
+#[test]
+fn test_clone_into() {
+    let source: LanguageIdentifier = "sl-Latn-SI-biske-rozaj".parse().unwrap();
+    let mut target: LanguageIdentifier = "de-1996-1901".parse().unwrap();
+    let allocation: *const subtags::Variant = target.variants().next().unwrap();
+
+    // `ToOwned::clone_into` relies on `clone_from`.
+    source.clone_into(&mut target);
+    assert_eq!(target, source);
+    assert_eq!(target.to_string(), "sl-Latn-SI-biske-rozaj");
+    // Same number of variants, so the allocation is reused.
+    assert!(std::ptr::eq(target.variants().next().unwrap(), allocation));
+
+    let source: LanguageIdentifier = "en-US-posix".parse().unwrap();
+    source.clone_into(&mut target);
+    assert_eq!(target, source);
+
+    let source: LanguageIdentifier = "und".parse().unwrap();
+    target.clone_from(&source);
+    assert_eq!(target, source);
+    assert_eq!(target.clone(), source);
+}
@@ -1,5 +1,9 @@
-use unic_langid_impl::likelysubtags::{maximize, minimize, CLDR_VERSION};
+use unic_langid_impl::likelysubtags::{
+    default_script_for, maximize, minimize, minimize_keeping_script, LikelySubtagsData,
+    CLDR_VERSION,
+};
 use unic_langid_impl::subtags;
+use unic_langid_impl::{LanguageIdentifier, LikelySubtagsError};
 
 static STRINGS: &[(&str, Option<&str>)] = &[
     ("en-US", Some("en-Latn-US")),
@@ -111,3 +115,230 @@ fn minimize_test() {
     let result = minimize(lang, Some(script), Some(region));
     assert_eq!(result, Some(extract_input("en")));
 }
+
+#[test]
+fn try_maximize_test() {
+    let mut langid: LanguageIdentifier = "sr-ME".parse().unwrap();
+    assert_eq!(langid.try_maximize(), Ok(true));
+    assert_eq!(&langid.to_string(), "sr-Latn-ME");
+    assert_eq!(langid.try_maximize(), Ok(false));
+
+    let mut langid: LanguageIdentifier = "tuq-Latn".parse().unwrap();
+    assert_eq!(langid.try_maximize(), Ok(false));
+    assert_eq!(&langid.to_string(), "tuq-Latn");
+
+    let mut langid: LanguageIdentifier = "zxx-US".parse().unwrap();
+    assert_eq!(langid.try_maximize(), Err(LikelySubtagsError::NoData));
+    assert_eq!(&langid.to_string(), "zxx-US");
+
+    for input in &["en-Latn-US", "sr-Latn-ME", "tuq-Latn-US", "ja-Latn-US"] {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid.try_maximize(), Ok(false), "{}", input);
+        assert_eq!(&langid.to_string(), input);
+    }
+
+    let mut langid: LanguageIdentifier = "zxx-Latn-US".parse().unwrap();
+    assert_eq!(langid.try_maximize(), Err(LikelySubtagsError::NoData));
+    assert_eq!(&langid.to_string(), "zxx-Latn-US");
+}
+
+#[test]
+fn minimize_keeping_script_test() {
+    let lang = "sr".parse().unwrap();
+    let script = "Cyrl".parse().unwrap();
+    let region = "RS".parse().unwrap();
+    let result = minimize_keeping_script(lang, Some(script), Some(region));
+    assert_eq!(result, Some(extract_input("sr-Cyrl")));
+
+    let lang = "en".parse().unwrap();
+    let result = minimize_keeping_script(lang, None, None);
+    assert_eq!(result, Some(extract_input("en-Latn")));
+
+    let lang = "sr".parse().unwrap();
+    let script = "Latn".parse().unwrap();
+    let region = "ME".parse().unwrap();
+    let result = minimize_keeping_script(lang, Some(script), Some(region));
+    assert_eq!(result, None);
+
+    let mut langid: LanguageIdentifier = "zh-Hant-TW".parse().unwrap();
+    assert!(langid.minimize_keeping_script());
+    assert_eq!(&langid.to_string(), "zh-Hant");
+}
+
+#[test]
+fn maximized_minimized_test() {
+    let langid: LanguageIdentifier = "zh-TW-macos".parse().unwrap();
+
+    let maximized = langid.maximized();
+    assert_eq!(&maximized.to_string(), "zh-Hant-TW-macos");
+    assert_eq!(&maximized.minimized().to_string(), "zh-TW-macos");
+    assert_eq!(&langid.to_string(), "zh-TW-macos");
+
+    let mut expected = langid.clone();
+    expected.maximize();
+    assert_eq!(maximized, expected);
+
+    let langid: LanguageIdentifier = "zxx-US".parse().unwrap();
+    assert_eq!(langid.maximized(), langid);
+    assert_eq!(langid.minimized(), langid);
+}
+
+#[test]
+fn maximize_und_test() {
+    let tests = &[
+        ("und", "en-Latn-US"),
+        ("und-US", "en-Latn-US"),
+        ("und-GB", "en-Latn-GB"),
+        ("und-FR", "fr-Latn-FR"),
+        ("und-Cyrl", "ru-Cyrl-RU"),
+        ("und-Arab", "ar-Arab-EG"),
+        ("und-Arab-MA", "ar-Arab-MA"),
+        ("und-419", "es-Latn-419"),
+    ];
+
+    for (input, output) in tests {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        assert!(langid.maximize(), "{}", input);
+        assert_eq!(&langid.to_string(), output);
+    }
+}
+
+#[test]
+fn script_or_likely_test() {
+    let cases = [
+        ("en", Some("Latn")),
+        ("sr", Some("Cyrl")),
+        ("sr-Latn", Some("Latn")),
+        ("zh-TW", Some("Hant")),
+        ("und-RU", Some("Cyrl")),
+        ("zxx", None),
+    ];
+    for (input, script) in &cases {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(
+            langid
+                .script_or_likely()
+                .as_ref()
+                .map(subtags::Script::as_str),
+            *script
+        );
+        assert_eq!(langid.to_string(), *input);
+    }
+}
+
+#[test]
+fn likely_subtags_data_test() {
+    let lang = |s: &str| {
+        let l: subtags::Language = s.parse().unwrap();
+        l.as_u64().unwrap()
+    };
+    let script = |s: &str| {
+        let s: subtags::Script = s.parse().unwrap();
+        s.as_u32()
+    };
+    let region = |s: &str| {
+        let r: subtags::Region = s.parse().unwrap();
+        r.as_u32()
+    };
+
+    let lang_only = [
+        (
+            lang("en"),
+            (Some(lang("en")), Some(script("Latn")), Some(region("GB"))),
+        ),
+        (
+            lang("sr"),
+            (Some(lang("sr")), Some(script("Latn")), Some(region("RS"))),
+        ),
+    ];
+    let region_only = [(
+        region("RS"),
+        (Some(lang("sr")), Some(script("Latn")), Some(region("RS"))),
+    )];
+    let data = LikelySubtagsData::new(&lang_only, &[], &[], &[], &[], &region_only).unwrap();
+
+    let mut langid: LanguageIdentifier = "en".parse().unwrap();
+    assert!(langid.maximize_with(&data));
+    assert_eq!(langid, "en-Latn-GB");
+    assert!(langid.minimize_with(&data));
+    assert_eq!(langid, "en");
+
+    let mut langid: LanguageIdentifier = "und-RS".parse().unwrap();
+    assert!(langid.maximize_with(&data));
+    assert_eq!(langid, "sr-Latn-RS");
+
+    let mut langid: LanguageIdentifier = "fr".parse().unwrap();
+    assert!(!langid.maximize_with(&data));
+    assert!(langid.maximize_with(LikelySubtagsData::cldr()));
+    assert_eq!(langid, "fr-Latn-FR");
+
+    let unsorted = [lang_only[1], lang_only[0]];
+    assert_eq!(
+        LikelySubtagsData::new(&unsorted, &[], &[], &[], &[], &[]).unwrap_err(),
+        LikelySubtagsError::InvalidData
+    );
+
+    let malformed = [(
+        lang("en"),
+        (Some(u64::from_le_bytes(*b"EN\0\0\0\0\0\0")), None, None),
+    )];
+    assert_eq!(
+        LikelySubtagsData::new(&malformed, &[], &[], &[], &[], &[]).unwrap_err(),
+        LikelySubtagsError::InvalidData
+    );
+
+    let empty = [(lang("en"), (None, Some(0), None))];
+    assert_eq!(
+        LikelySubtagsData::new(&empty, &[], &[], &[], &[], &[]).unwrap_err(),
+        LikelySubtagsError::InvalidData
+    );
+}
+
+#[test]
+fn default_script_for_test() {
+    let cases = [
+        ("en", Some("Latn")),
+        ("sr", Some("Cyrl")),
+        ("ru", Some("Cyrl")),
+        ("ar", Some("Arab")),
+        ("zh", Some("Hans")),
+        ("ja", Some("Jpan")),
+        ("und", Some("Latn")),
+        ("zxx", None),
+        ("e", None),
+        ("en-US", None),
+    ];
+    for (lang, script) in &cases {
+        assert_eq!(
+            default_script_for(lang)
+                .as_ref()
+                .map(subtags::Script::as_str),
+            *script,
+            "{}",
+            lang
+        );
+    }
+}
+
+#[test]
+fn likely_subtags_preserve_variants_test() {
+    let cases = [
+        ("de-1996", "de-Latn-DE-1996", "de-1996"),
+        ("sl-rozaj-biske", "sl-Latn-SI-biske-rozaj", "sl-biske-rozaj"),
+        ("ca-ES-valencia", "ca-Latn-ES-valencia", "ca-valencia"),
+        ("en-Latn-US-posix", "en-Latn-US-posix", "en-posix"),
+    ];
+    for (input, maximized, minimized) in &cases {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        langid.maximize();
+        assert_eq!(langid.to_string(), *maximized);
+        assert_eq!(langid.maximized().to_string(), *maximized);
+
+        assert!(langid.minimize());
+        assert_eq!(langid.to_string(), *minimized);
+        assert_eq!(
+            langid.variant_count(),
+            input.parse::<LanguageIdentifier>().unwrap().variant_count()
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use unic_langid_impl::{negotiate_languages, LanguageIdentifier, NegotiationStrategy};
+
+fn langids(input: &[&str]) -> Vec<LanguageIdentifier> {
+    input
+        .iter()
+        .map(|s| s.parse().expect("Parsing failed."))
+        .collect()
+}
+
+fn negotiate(
+    requested: &[&str],
+    available: &[&str],
+    default: Option<&str>,
+    strategy: NegotiationStrategy,
+) -> Vec<String> {
+    let requested = langids(requested);
+    let available = langids(available);
+    let default: Option<LanguageIdentifier> = default.map(|s| s.parse().expect("Parsing failed."));
+    negotiate_languages(&requested, &available, default.as_ref(), strategy)
+        .into_iter()
+        .map(|langid| langid.to_string())
+        .collect()
+}
+
+#[test]
+fn test_filtering() {
+    let tests: &[(&[&str], &[&str], &[&str])] = &[
+        (&["en"], &["en"], &["en"]),
+        (&["en-US"], &["en-US"], &["en-US"]),
+        (&["en"], &["en-US"], &["en-US"]),
+        (&["en-US"], &["en"], &["en"]),
+        (&["en-GB"], &["en-US"], &["en-US"]),
+        (&["en"], &["en-Latn-US"], &["en-Latn-US"]),
+        (&["en-US-macos"], &["en-US"], &["en-US"]),
+        (&["sr-RU"], &["sr-Cyrl", "sr-Latn"], &["sr-Latn"]),
+        (&["zh-TW"], &["zh-Hans", "zh-Hant"], &["zh-Hant"]),
+        (
+            &["de-AT", "en"],
+            &["en-US", "de-DE", "fr"],
+            &["de-DE", "en-US"],
+        ),
+        (
+            &["en", "de"],
+            &["de", "en-GB", "en-US"],
+            &["en-US", "en-GB", "de"],
+        ),
+        (&["fr"], &["en-US", "de"], &[]),
+    ];
+
+    for (requested, available, expected) in tests {
+        assert_eq!(
+            negotiate(requested, available, None, NegotiationStrategy::Filtering),
+            *expected,
+            "{:?} {:?}",
+            requested,
+            available
+        );
+    }
+}
+
+#[test]
+fn test_matching() {
+    let tests: &[(&[&str], &[&str], &[&str])] = &[
+        (&["en"], &["en-US", "en-GB"], &["en-US"]),
+        (&["en", "de"], &["de", "en-GB", "en-US"], &["en-US", "de"]),
+        (&["fr"], &["en-US", "de"], &[]),
+    ];
+
+    for (requested, available, expected) in tests {
+        assert_eq!(
+            negotiate(requested, available, None, NegotiationStrategy::Matching),
+            *expected
+        );
+    }
+}
+
+#[test]
+fn test_lookup() {
+    assert_eq!(
+        negotiate(
+            &["de-AT", "en"],
+            &["en-US", "de-DE"],
+            Some("en-US"),
+            NegotiationStrategy::Lookup
+        ),
+        vec!["de-DE"]
+    );
+    assert_eq!(
+        negotiate(
+            &["fr"],
+            &["en-US", "de-DE"],
+            Some("en-US"),
+            NegotiationStrategy::Lookup
+        ),
+        vec!["en-US"]
+    );
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(
+        negotiate(
+            &["fr"],
+            &["en-US", "de-DE"],
+            Some("en-US"),
+            NegotiationStrategy::Filtering
+        ),
+        vec!["en-US"]
+    );
+    assert_eq!(
+        negotiate(
+            &["de"],
+            &["en-US", "de-DE"],
+            Some("en-US"),
+            NegotiationStrategy::Matching
+        ),
+        vec!["de-DE", "en-US"]
+    );
+    assert_eq!(
+        negotiate(
+            &["en"],
+            &["en-US", "de-DE"],
+            Some("en-US"),
+            NegotiationStrategy::Filtering
+        ),
+        vec!["en-US"]
+    );
+}
@@ -0,0 +1,48 @@
+use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+
+#[test]
+fn test_parse_with_options() {
+    let preserve = ParserOptions {
+        normalize_case: false,
+        reorder_variants: false,
+    };
+    let keep_case = ParserOptions {
+        normalize_case: false,
+        reorder_variants: true,
+    };
+    let keep_order = ParserOptions {
+        normalize_case: true,
+        reorder_variants: false,
+    };
+    let input = "SR_latn_rs_Rozaj_1994";
+
+    let tests = &[
+        (ParserOptions::default(), "sr-Latn-RS-1994-rozaj"),
+        (preserve, "SR-latn-rs-Rozaj-1994"),
+        (keep_case, "SR-latn-rs-1994-Rozaj"),
+        (keep_order, "sr-Latn-RS-rozaj-1994"),
+    ];
+    for (options, output) in tests {
+        let parsed = LanguageIdentifier::parse_with_options(input, *options).unwrap();
+        assert_eq!(parsed.as_str(), *output);
+        assert_eq!(&parsed.to_string(), output);
+        assert_eq!(*parsed, "sr-Latn-RS-1994-rozaj");
+        assert_eq!(parsed.language, "sr");
+        assert!(parsed.has_variant_str("rozaj"));
+    }
+
+    let parsed = LanguageIdentifier::parse_with_options("UND", preserve).unwrap();
+    assert_eq!(parsed.to_string(), "UND");
+    assert!(parsed.language.is_empty());
+
+    // Grandfathered tags are replaced, so the input can't be preserved.
+    let parsed = LanguageIdentifier::parse_with_options("I-KLINGON", preserve).unwrap();
+    assert_eq!(parsed.to_string(), "tlh");
+
+    assert!(LanguageIdentifier::parse_with_options("en-US-", preserve).is_err());
+
+    let langid: LanguageIdentifier = "EN_us".parse().unwrap();
+    let parsed = LanguageIdentifier::parse_with_options("EN_us", ParserOptions::default()).unwrap();
+    assert_eq!(parsed.to_string(), langid.to_string());
+    assert_eq!(parsed.into_langid(), langid);
+}
@@ -0,0 +1,23 @@
+use unic_langid_impl::subtags;
+
+#[test]
+fn test_region_containment() {
+    let region = |s: &str| -> subtags::Region { s.parse().unwrap() };
+
+    assert!(region("FR").contained_in(&region("155")));
+    assert!(region("FR").contained_in(&region("150")));
+    assert!(region("FR").contained_in(&region("001")));
+    assert!(!region("FR").contained_in(&region("FR")));
+    assert!(!region("FR").contained_in(&region("019")));
+    assert!(region("MX").contained_in(&region("419")));
+    assert!(region("BR").contained_in(&region("019")));
+    assert!(!region("US").contained_in(&region("419")));
+    assert!(region("150").contained_in(&region("001")));
+    assert!(region("AQ").contained_in(&region("009")));
+
+    assert_eq!(region("FR").children().count(), 0);
+    assert_eq!(
+        region("150").children().collect::<Vec<_>>(),
+        &[region("039"), region("151"), region("154"), region("155")]
+    );
+}
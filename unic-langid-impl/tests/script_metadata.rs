@@ -0,0 +1,34 @@
+use unic_langid_impl::subtags;
+use unic_langid_impl::CharacterDirection;
+
+#[test]
+fn test_script_metadata() {
+    let tests = &[
+        ("Arab", CharacterDirection::RTL, '\u{628}', false),
+        ("Hebr", CharacterDirection::RTL, '\u{5D0}', false),
+        ("Latn", CharacterDirection::LTR, 'L', true),
+        ("Cyrl", CharacterDirection::LTR, '\u{42F}', true),
+        ("Hani", CharacterDirection::LTR, '\u{5B57}', false),
+    ];
+
+    for (input, direction, sample_char, has_case) in tests {
+        let script: subtags::Script = input.parse().unwrap();
+        let metadata = script.metadata().expect(input);
+        assert_eq!(metadata.direction, *direction, "{}", input);
+        assert_eq!(metadata.sample_char, *sample_char, "{}", input);
+        assert_eq!(metadata.has_case, *has_case, "{}", input);
+    }
+
+    let script: subtags::Script = "Qaaa".parse().unwrap();
+    assert_eq!(script.metadata(), None);
+
+    for input in &["Adlm", "Chrs", "Ougr", "Thaa", "Deva", "Grek"] {
+        let script: subtags::Script = input.parse().unwrap();
+        assert_eq!(
+            script.metadata().unwrap().direction,
+            unic_langid_impl::script_direction(input),
+            "{}",
+            input
+        );
+    }
+}
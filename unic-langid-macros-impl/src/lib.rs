@@ -6,18 +6,36 @@ use proc_macro_hack::proc_macro_hack;
 use quote::quote;
 use syn::{parse_macro_input, LitStr};
 
+use std::fmt::Display;
+use std::str::FromStr;
+
 use unic_langid_impl::{subtags, LanguageIdentifier};
 
+/// Parses the literal, producing a compile error pointing at it on failure.
+fn parse_literal<T>(lit: &LitStr, kind: &str) -> Result<T, TokenStream>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lit.value().parse().map_err(|err| {
+        let msg = format!("Malformed {} \"{}\": {}", kind, lit.value(), err);
+        TokenStream::from(syn::Error::new(lit.span(), msg).to_compile_error())
+    })
+}
+
 #[proc_macro_hack]
 pub fn lang(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
-    let parsed: subtags::Language = id.value().parse().expect("Malformed Language Subtag");
+    let parsed: subtags::Language = match parse_literal(&id, "Language Subtag") {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
 
     let lang: Option<u64> = parsed.into();
     let lang = if let Some(lang) = lang {
         quote!(unsafe { $crate::subtags::Language::from_raw_unchecked(#lang) })
     } else {
-        quote!(None)
+        quote!($crate::subtags::Language::und())
     };
 
     TokenStream::from(quote! {
@@ -28,7 +46,10 @@ pub fn lang(input: TokenStream) -> TokenStream {
 #[proc_macro_hack]
 pub fn script(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
-    let parsed: subtags::Script = id.value().parse().expect("Malformed Script Subtag");
+    let parsed: subtags::Script = match parse_literal(&id, "Script Subtag") {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
 
     let script: u32 = parsed.into();
 
@@ -40,7 +61,10 @@ pub fn script(input: TokenStream) -> TokenStream {
 #[proc_macro_hack]
 pub fn region(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
-    let parsed: subtags::Region = id.value().parse().expect("Malformed Region Subtag");
+    let parsed: subtags::Region = match parse_literal(&id, "Region Subtag") {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
 
     let region: u32 = parsed.into();
 
@@ -52,7 +76,10 @@ pub fn region(input: TokenStream) -> TokenStream {
 #[proc_macro_hack]
 pub fn variant_fn(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
-    let parsed: subtags::Variant = id.value().parse().expect("Malformed Variant Subtag");
+    let parsed: subtags::Variant = match parse_literal(&id, "Variant Subtag") {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
 
     let variant: u64 = parsed.into();
 
@@ -64,7 +91,10 @@ pub fn variant_fn(input: TokenStream) -> TokenStream {
 #[proc_macro_hack]
 pub fn langid(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
-    let parsed: LanguageIdentifier = id.value().parse().expect("Malformed Language Identifier");
+    let parsed: LanguageIdentifier = match parse_literal(&id, "Language Identifier") {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
 
     let (lang, script, region, variants) = parsed.into_parts();
 
@@ -72,7 +102,7 @@ pub fn langid(input: TokenStream) -> TokenStream {
     let lang = if let Some(lang) = lang {
         quote!(unsafe { $crate::subtags::Language::from_raw_unchecked(#lang) })
     } else {
-        quote!($crate::subtags::Language::default())
+        quote!($crate::subtags::Language::und())
     };
 
     let script = if let Some(script) = script {
@@ -103,6 +133,6 @@ pub fn langid(input: TokenStream) -> TokenStream {
     };
 
     TokenStream::from(quote! {
-        unsafe { $crate::LanguageIdentifier::from_raw_parts_unchecked(#lang, #script, #region, #variants) }
+        $crate::LanguageIdentifier::from_raw_parts_unchecked(#lang, #script, #region, #variants)
     })
 }
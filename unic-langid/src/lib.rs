@@ -70,7 +70,17 @@
 //! The macros produce instances of `LanguageIdentifier` the same way as parsing from `&str` does,
 //! but since the parsing is performed at build time, it doesn't need a `Result`.
 //!
-//! At the moment `langid!` can also be used for const variables, but only if no variants are used.
+//! `langid!` expands to a constant expression as long as no variants are used, so it can
+//! initialize `const` and `static` items without lazy initialization:
+//!
+//! ``` ignore
+//! use unic_langid::{langid, LanguageIdentifier};
+//!
+//! static EN_US: LanguageIdentifier = langid!("en-US");
+//! ```
+//!
+//! Without the macros, `LanguageIdentifier::from_raw_parts_unchecked` is a `const fn` which can
+//! be combined with `Language::und` and the `from_raw_unchecked` subtag constructors.
 //!
 //! The macros are optional to reduce the dependency chain and compilation time of `unic-langid`.
 //!
@@ -100,11 +110,37 @@
 //! ```
 //!
 //! The feature is optional because it increases the binary size of the library by including
-//! a data table for CLDR likelySubtags.
+//! a data table for CLDR likelySubtags. In a size-optimized release build of a small program
+//! which parses and maximizes an identifier, the table adds roughly 75 KiB.
+//!
+//! ## `no_std`
+//!
+//! The crate is `no_std` and only requires `alloc`. The default `std` feature adds
+//! implementations of `std::error::Error` for the error types. Disable default features
+//! to use the crate without `std`:
+//!
+//! ```toml
+//! unic-langid = { version = "0.9", default-features = false, features = ["direction"] }
+//! ```
+//!
+//! The macros still require `std`.
+//!
+//! ## `direction`
+//!
+//! The default `direction` feature provides `LanguageIdentifier::character_direction`,
+//! `script_direction`, `character_direction_for_language`, `rtl_languages`, `CLDR_VERSION`
+//! and `Script::metadata`, backed by the CLDR layout and scriptMetadata tables. Leave it
+//! out of the features for a parser-only build.
+//!
+//! Tables which are never used are dropped by the linker, so enabling the feature alone does
+//! not change the size of a stripped release binary. Calling `character_direction` adds
+//! roughly 1 KiB, and calling `Script::metadata` roughly another 13 KiB.
 //!
 //! [`UTS #35: Unicode LDML 3.1 Unicode Language Identifier`]: https://unicode.org/reports/tr35/tr35.html#Unicode_language_identifier
 //! [`LanguageIdentifier`]: ./struct.LanguageIdentifier.html
 
+#![no_std]
+
 pub use unic_langid_impl::*;
 
 #[cfg(feature = "unic-langid-macros")]
@@ -1,6 +1,6 @@
 use unic_langid::LanguageIdentifier;
 #[cfg(feature = "unic-langid-macros")]
-use unic_langid::{langid, langid_slice, langids};
+use unic_langid::{lang, langid, langid_slice, langids};
 
 #[test]
 fn basic_test() {
@@ -18,6 +18,17 @@ fn langid_macro_test() {
     const _: LanguageIdentifier = langid!("en-US");
 }
 
+#[test]
+#[cfg(feature = "unic-langid-macros")]
+fn langid_macro_static_test() {
+    static EN_US: LanguageIdentifier = langid!("en-US");
+    const UND_LATN: LanguageIdentifier = langid!("und-Latn");
+
+    assert_eq!(EN_US, "en-US");
+    assert_eq!(UND_LATN, "und-Latn");
+    assert_eq!(lang!("und"), "und");
+}
+
 #[test]
 #[cfg(feature = "unic-langid-macros")]
 fn langids_macro_test() {
@@ -67,6 +78,6 @@ fn langid_ord() {
             .iter()
             .map(|l: &LanguageIdentifier| l.to_string())
             .collect::<Vec<_>>(),
-        &["en-US", "en-Latn"]
+        &["en-Latn", "en-US"]
     );
 }
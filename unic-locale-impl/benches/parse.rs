@@ -0,0 +1,51 @@
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+use unic_locale_impl::parser::parse_locale;
+
+fn parse_bench(c: &mut Criterion) {
+    let sets: &[(&str, &[&str])] = &[
+        ("language_only", &["en", "pl", "zh", "ast", "und"]),
+        (
+            "full",
+            &[
+                "en-US",
+                "zh-Hans-CN",
+                "sr-Cyrl-RS",
+                "de-CH-1996",
+                "en-Latn-US-macos-posix",
+            ],
+        ),
+        (
+            "extensions",
+            &[
+                "en-US-u-ca-gregory-hc-h12-nu-latn",
+                "de-u-co-phonebk-ka-shifted-kn",
+                "ja-Kana-t-it-h0-hybrid-x-private",
+                "und-Latn-t-und-cyrl-m0-ungegn",
+                "fr-CA-a-foo-t-en-u-attr-ca-buddhist-x-foo-bar",
+            ],
+        ),
+    ];
+
+    let mut group = c.benchmark_group("parse");
+    for (name, strings) in sets {
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                for s in strings.iter() {
+                    let _ = parse_locale(black_box(s));
+                }
+            })
+        });
+    }
+    group.finish();
+
+    c.bench_function("parse/en-US", |b| {
+        b.iter(|| parse_locale(black_box("en-US")))
+    });
+}
+
+criterion_group!(benches, parse_bench,);
+criterion_main!(benches);
@@ -9,7 +9,7 @@
 //!  * Other extensions - marked as any `a-z` except of `u`, `t` and `x`.
 mod private;
 mod transform;
-mod unicode;
+pub(crate) mod unicode;
 
 pub use private::PrivateExtensionList;
 pub use transform::TransformExtensionList;
@@ -62,7 +62,26 @@ impl std::fmt::Display for ExtensionType {
     }
 }
 
-/// A map of extensions associated with a given `Locale.
+/// A map of extensions associated with a given `Locale`.
+///
+/// The map is exposed as the public `extensions` field of `Locale`
+/// and can be modified in place.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+///
+/// let mut loc: Locale = "en-US".parse()
+///     .expect("Parsing failed.");
+///
+/// loc.extensions.unicode.set_keyword("ca", &["gregory"])
+///     .expect("Setting keyword failed.");
+/// loc.extensions.unicode.set_keyword("nu", &["latn"])
+///     .expect("Setting keyword failed.");
+///
+/// assert_eq!(loc.to_string(), "en-US-u-ca-gregory-nu-latn");
+/// ```
 #[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
 pub struct ExtensionsMap {
     pub unicode: UnicodeExtensionList,
@@ -72,6 +91,28 @@ pub struct ExtensionsMap {
 }
 
 impl ExtensionsMap {
+    /// Returns an iterator over the extensions other than `u`, `t` and `x`,
+    /// yielding the singleton and a value subtag for every subtag, ordered
+    /// by singleton.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-s-bar-a-foo-baz".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.other_extensions().collect::<Vec<_>>(),
+    ///            &[('a', "foo"), ('a', "baz"), ('s', "bar")]);
+    /// assert_eq!(loc.to_string(), "en-US-a-foo-baz-s-bar");
+    /// ```
+    pub fn other_extensions(&self) -> impl Iterator<Item = (char, &str)> {
+        self.other
+            .iter()
+            .flat_map(|(ext, values)| values.iter().map(move |v| (*ext, v.as_str())))
+    }
+
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParserError> {
         let mut iterator = bytes.split(|c| *c == b'-' || *c == b'_').peekable();
         Self::try_from_iter(&mut iterator)
@@ -84,7 +125,10 @@ impl ExtensionsMap {
 
         let mut st = iter.next();
         while let Some(subtag) = st {
-            match subtag.get(0).map(|b| ExtensionType::from_byte(*b)) {
+            if subtag.len() > 1 {
+                return Err(ParserError::InvalidExtension);
+            }
+            match subtag.first().map(|b| ExtensionType::from_byte(*b)) {
                 Some(Ok(ExtensionType::Unicode)) => {
                     result.unicode = UnicodeExtensionList::try_from_iter(iter)?;
                 }
@@ -94,8 +138,25 @@ impl ExtensionsMap {
                 Some(Ok(ExtensionType::Private)) => {
                     result.private = PrivateExtensionList::try_from_iter(iter)?;
                 }
+                Some(Ok(ExtensionType::Other(ext))) => {
+                    if result.other.contains_key(&ext) {
+                        return Err(ParserError::InvalidExtension);
+                    }
+                    let mut values = vec![];
+                    while let Some(subtag) = iter.peek() {
+                        if subtag.len() == 1 {
+                            break;
+                        }
+                        values.push(parse_other_value(subtag)?);
+                        iter.next();
+                    }
+                    if values.is_empty() {
+                        return Err(ParserError::InvalidExtension);
+                    }
+                    result.other.insert(ext, values);
+                }
                 None => {}
-                _ => unimplemented!(),
+                _ => return Err(ParserError::InvalidExtension),
             }
 
             st = iter.next();
@@ -104,11 +165,47 @@ impl ExtensionsMap {
         Ok(result)
     }
 
+    /// Returns `true` if there are no unicode, transform, private or other
+    /// extensions in the `ExtensionsMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.is_empty(), false);
+    /// ```
     pub fn is_empty(&self) -> bool {
-        self.unicode.is_empty() && self.transform.is_empty() && self.private.is_empty()
+        self.unicode.is_empty()
+            && self.transform.is_empty()
+            && self.private.is_empty()
+            && self.other.is_empty()
+    }
+
+    /// Merges the extensions of `other` into the `ExtensionsMap`, with the
+    /// values of `other` taking precedence on conflicts.
+    pub(crate) fn merge_from(&mut self, other: &Self) {
+        self.unicode.merge_from(&other.unicode);
+        self.transform.merge_from(&other.transform);
+        for (ext, values) in &other.other {
+            self.other.insert(*ext, values.clone());
+        }
+        self.private.merge_from(&other.private);
     }
 }
 
+fn parse_other_value(t: &[u8]) -> Result<TinyStr8, ParserError> {
+    let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
+    if t.len() < 2 || t.len() > 8 || !s.is_ascii_alphanumeric() {
+        return Err(ParserError::InvalidSubtag);
+    }
+
+    Ok(s.to_ascii_lowercase())
+}
+
 impl FromStr for ExtensionsMap {
     type Err = ParserError;
 
@@ -119,8 +216,26 @@ impl FromStr for ExtensionsMap {
 
 impl std::fmt::Display for ExtensionsMap {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        // Alphabetic by singleton (t, u, x)
-        write!(f, "{}{}{}", self.transform, self.unicode, self.private)?;
+        // Alphabetic by singleton, with the private use extension last.
+        fn write_other<'a>(
+            f: &mut std::fmt::Formatter,
+            other: impl Iterator<Item = (&'a char, &'a Vec<TinyStr8>)>,
+        ) -> std::fmt::Result {
+            for (ext, values) in other {
+                write!(f, "-{}", ext)?;
+                for value in values {
+                    write!(f, "-{}", value)?;
+                }
+            }
+            Ok(())
+        }
+
+        write_other(f, self.other.range(..'t'))?;
+        write!(f, "{}", self.transform)?;
+        write_other(f, self.other.range('t'..'u'))?;
+        write!(f, "{}", self.unicode)?;
+        write_other(f, self.other.range('u'..))?;
+        write!(f, "{}", self.private)?;
 
         Ok(())
     }
@@ -6,7 +6,8 @@ use tinystr::TinyStr8;
 /// A list of [`Unicode Private Extensions`] as defined in [`Unicode Locale
 /// Identifier`] specification.
 ///
-/// Those extensions are intended for `pass-through` use.
+/// Those extensions are intended for `pass-through` use, so the tags
+/// are kept in the order in which they were provided.
 ///
 /// # Examples
 ///
@@ -17,7 +18,7 @@ use tinystr::TinyStr8;
 ///     .expect("Parsing failed.");
 ///
 /// assert_eq!(loc.extensions.private.has_tag("faa"), Ok(true));
-/// assert_eq!(loc.extensions.private.tags().next(), Some("faa")); // tags got sorted
+/// assert_eq!(loc.extensions.private.tags().next(), Some("foo")); // order is preserved
 /// loc.extensions.private.clear_tags();
 /// assert_eq!(loc.to_string(), "en-US");
 /// ```
@@ -82,13 +83,13 @@ impl PrivateExtensionList {
     ///     .expect("Parsing failed.");
     ///
     /// assert_eq!(loc.extensions.private.tags().collect::<Vec<_>>(),
-    ///            &["bar", "foo"]);
+    ///            &["foo", "bar"]);
     /// ```
     pub fn tags(&self) -> impl ExactSizeIterator<Item = &str> {
         self.0.iter().map(|s| s.as_ref())
     }
 
-    /// Adds a tag to the `PrivateExtensionList`.
+    /// Appends a tag to the `PrivateExtensionList`.
     ///
     /// # Examples
     ///
@@ -102,14 +103,18 @@ impl PrivateExtensionList {
     ///     .expect("Adding tag failed.");
     ///
     /// assert_eq!(loc.to_string(), "en-US-x-foo");
+    ///
+    /// loc.extensions.private.add_tag("bar")
+    ///     .expect("Adding tag failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-x-foo-bar");
     /// ```
     pub fn add_tag<S: AsRef<[u8]>>(&mut self, tag: S) -> Result<(), LocaleError> {
         self.0.push(parse_value(tag.as_ref())?);
-        self.0.sort_unstable();
         Ok(())
     }
 
-    /// Removes a tag from the `PrivateExtensionList`.
+    /// Removes the first occurrence of a tag from the `PrivateExtensionList`.
     ///
     /// Returns `true` if tag was included in the `PrivateExtensionList` before
     /// removal.
@@ -130,12 +135,12 @@ impl PrivateExtensionList {
     /// ```
     pub fn remove_tag<S: AsRef<[u8]>>(&mut self, tag: S) -> Result<bool, LocaleError> {
         let value = parse_value(tag.as_ref())?;
-        match self.0.binary_search(&value) {
-            Ok(idx) => {
+        match self.0.iter().position(|t| *t == value) {
+            Some(idx) => {
                 self.0.remove(idx);
                 Ok(true)
             }
-            Err(_) => Ok(false),
+            None => Ok(false),
         }
     }
 
@@ -156,6 +161,15 @@ impl PrivateExtensionList {
         self.0.clear();
     }
 
+    /// Appends the tags of `other` which are not included yet.
+    pub(crate) fn merge_from(&mut self, other: &Self) {
+        for tag in &other.0 {
+            if !self.0.contains(tag) {
+                self.0.push(*tag);
+            }
+        }
+    }
+
     pub(crate) fn try_from_iter<'a>(
         iter: &mut impl Iterator<Item = &'a [u8]>,
     ) -> Result<Self, ParserError> {
@@ -164,7 +178,6 @@ impl PrivateExtensionList {
         for subtag in iter {
             pext.0.push(parse_value(subtag)?);
         }
-        pext.0.sort_unstable();
 
         Ok(pext)
     }
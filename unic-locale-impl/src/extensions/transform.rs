@@ -39,7 +39,7 @@ use tinystr::{TinyStr4, TinyStr8};
 /// [`Unicode Locale Identifier`]: https://unicode.org/reports/tr35/#Unicode_locale_identifier
 #[derive(Clone, PartialEq, Eq, Debug, Default, Hash, PartialOrd, Ord)]
 pub struct TransformExtensionList {
-    tlang: Option<LanguageIdentifier>,
+    pub(crate) tlang: Option<LanguageIdentifier>,
 
     // Canonical: sort by key (BTreeMap is already) / remove value 'true'
     tfields: BTreeMap<TinyStr4, Vec<TinyStr8>>,
@@ -70,9 +70,14 @@ fn parse_tvalue(t: &[u8]) -> Result<Option<TinyStr8>, ParserError> {
     }
 }
 
+fn is_tvalue(t: &[u8]) -> bool {
+    let slen = t.len();
+    (3..=8).contains(&slen) && !t.iter().any(|c: &u8| !c.is_ascii_alphanumeric())
+}
+
 fn is_language_subtag(t: &[u8]) -> bool {
     let slen = t.len();
-    (slen >= 2 && slen <= 8 || slen == 4) && !t.iter().any(|c: &u8| !c.is_ascii_alphabetic())
+    ((2..=8).contains(&slen) || slen == 4) && !t.iter().any(|c: &u8| !c.is_ascii_alphabetic())
 }
 
 impl TransformExtensionList {
@@ -183,7 +188,7 @@ impl TransformExtensionList {
         tkey: S,
     ) -> Result<impl ExactSizeIterator<Item = &str>, LocaleError> {
         let tfields: &[_] = match self.tfields.get(&parse_tkey(tkey.as_ref())?) {
-            Some(ref v) => &**v,
+            Some(v) => v,
             None => &[],
         };
 
@@ -207,6 +212,35 @@ impl TransformExtensionList {
         self.tfields.keys().map(|s| s.as_ref())
     }
 
+    /// Returns an iterator over all tfields in the `TransformExtensionList`
+    /// as `(tkey, tvalue)` pairs, in canonical tkey order.
+    ///
+    /// A tvalue made of multiple subtags yields one pair per subtag, and
+    /// a `true` tvalue yields `"true"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "de-t-de-1996-k0-dvorak-h0-hybrid".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.transform.transform_fields().collect::<Vec<_>>(),
+    ///            &[("h0", "hybrid"), ("k0", "dvorak")]);
+    /// ```
+    pub fn transform_fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.tfields.iter().flat_map(|(k, t)| {
+            let key: &str = k.as_ref();
+            let values: &[TinyStr8] = if t.is_empty() {
+                std::slice::from_ref(&TRUE_TVALUE)
+            } else {
+                t
+            };
+            values.iter().map(move |v| (key, v.as_ref()))
+        })
+    }
+
     /// Adds a tfield to the `TransformExtensionList` or sets tvalue for tkey if
     /// tfield is already included in the `TransformExtensionList`.
     ///
@@ -280,8 +314,19 @@ impl TransformExtensionList {
         self.tfields.clear();
     }
 
+    /// Sets the tlang and tfields of `other`, replacing the existing tlang
+    /// and the values of existing tkeys.
+    pub(crate) fn merge_from(&mut self, other: &Self) {
+        if let Some(tlang) = &other.tlang {
+            self.tlang = Some(tlang.clone());
+        }
+        for (key, value) in &other.tfields {
+            self.tfields.insert(*key, value.clone());
+        }
+    }
+
     pub(crate) fn try_from_iter<'a>(
-        mut iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
     ) -> Result<Self, ParserError> {
         let mut text = Self::default();
 
@@ -299,14 +344,14 @@ impl TransformExtensionList {
                 }
                 current_tkey = Some(parse_tkey(subtag)?);
                 iter.next();
-            } else if current_tkey.is_some() {
+            } else if current_tkey.is_some() && is_tvalue(subtag) {
                 if let Some(tval) = parse_tvalue(subtag)? {
                     current_tvalue.push(tval);
                 }
                 iter.next();
             } else if is_language_subtag(subtag) {
                 text.tlang = Some(
-                    LanguageIdentifier::try_from_iter(&mut iter, true)
+                    LanguageIdentifier::try_from_iter(iter, true)
                         .map_err(|_| ParserError::InvalidLanguage)?,
                 );
             } else {
@@ -1,6 +1,7 @@
 use crate::errors::LocaleError;
 use crate::parser::ParserError;
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::iter::Peekable;
 use std::ops::RangeInclusive;
@@ -52,8 +53,19 @@ fn parse_key(key: &[u8]) -> Result<TinyStr4, ParserError> {
     Ok(key.to_ascii_lowercase())
 }
 
+pub(crate) const CALENDAR_KEY: TinyStr4 = unsafe { TinyStr4::new_unchecked(24_931u32) }; // "ca"
+pub(crate) const NUMBERING_SYSTEM_KEY: TinyStr4 = unsafe { TinyStr4::new_unchecked(30_062u32) }; // "nu"
+
 const TRUE_TYPE: TinyStr8 = unsafe { TinyStr8::new_unchecked(1_702_195_828u64) }; // "true"
 
+fn join_types(value: &[TinyStr8]) -> String {
+    value
+        .iter()
+        .map(|t| t.as_str())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
 fn parse_type(t: &[u8]) -> Result<Option<TinyStr8>, ParserError> {
     let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
     if !TYPE_LENGTH.contains(&t.len()) || !s.is_ascii_alphanumeric() {
@@ -106,6 +118,22 @@ impl UnicodeExtensionList {
         self.keywords.is_empty() && self.attributes.is_empty()
     }
 
+    /// Returns the number of keywords in the `UnicodeExtensionList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-foo-ca-buddhist-kn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.unicode.keywords_len(), 2);
+    /// ```
+    pub fn keywords_len(&self) -> usize {
+        self.keywords.len()
+    }
+
     /// Returns the value of keyword in the `UnicodeExtensionList`.
     ///
     /// # Examples
@@ -131,12 +159,16 @@ impl UnicodeExtensionList {
         &self,
         key: S,
     ) -> Result<impl ExactSizeIterator<Item = &str>, LocaleError> {
-        let keywords: &[_] = match self.keywords.get(&parse_key(key.as_ref())?) {
-            Some(ref v) => &**v,
+        Ok(self.keyword_by_key(&parse_key(key.as_ref())?))
+    }
+
+    pub(crate) fn keyword_by_key(&self, key: &TinyStr4) -> impl ExactSizeIterator<Item = &str> {
+        let keywords: &[_] = match self.keywords.get(key) {
+            Some(v) => v,
             None => &[],
         };
 
-        Ok(keywords.iter().map(|s| s.as_ref()))
+        keywords.iter().map(|s| s.as_ref())
     }
 
     /// Returns an iterator over all keys in the `UnicodeExtensionList`.
@@ -178,8 +210,14 @@ impl UnicodeExtensionList {
     /// assert_eq!(loc.to_string(), "en-US-u-ca-chinese");
     /// ```
     pub fn set_keyword<S: AsRef<[u8]>>(&mut self, key: S, value: &[S]) -> Result<(), LocaleError> {
-        let key = parse_key(key.as_ref())?;
+        self.set_keyword_by_key(parse_key(key.as_ref())?, value)
+    }
 
+    pub(crate) fn set_keyword_by_key<S: AsRef<[u8]>>(
+        &mut self,
+        key: TinyStr4,
+        value: &[S],
+    ) -> Result<(), LocaleError> {
         let t = value
             .iter()
             .filter_map(|t| parse_type(t.as_ref()).transpose())
@@ -189,6 +227,50 @@ impl UnicodeExtensionList {
         Ok(())
     }
 
+    /// Replaces all keywords of the `UnicodeExtensionList` with the ones produced
+    /// by an iterator of `(key, value)` pairs.
+    ///
+    /// A value may consist of multiple subtags separated by `-`. If a key appears
+    /// more than once the last value is used. Attributes are left untouched.
+    ///
+    /// If any key or value is invalid, an error is returned and the keywords
+    /// are not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.extensions.unicode.set_from_iter(vec![
+    ///     ("ca", "islamic-civil"),
+    ///     ("nu", "arab"),
+    ///     ("ca", "buddhist"),
+    /// ]).expect("Setting keywords failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-u-ca-buddhist-nu-arab");
+    ///
+    /// assert!(loc.extensions.unicode.set_from_iter(vec![("c", "buddhist")]).is_err());
+    /// ```
+    pub fn set_from_iter<'a, I>(&mut self, iter: I) -> Result<(), LocaleError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut keywords = BTreeMap::new();
+        for (key, value) in iter {
+            let key = parse_key(key.as_bytes())?;
+            let t = value
+                .split('-')
+                .filter_map(|t| parse_type(t.as_bytes()).transpose())
+                .collect::<Result<Vec<_>, _>>()?;
+            keywords.insert(key, t);
+        }
+        self.keywords = keywords;
+        Ok(())
+    }
+
     /// Removes a keyword from the `UnicodeExtensionList`.
     ///
     /// Returns `true` if keyword was included in the `UnicodeExtensionList`
@@ -212,6 +294,44 @@ impl UnicodeExtensionList {
         Ok(self.keywords.remove(&parse_key(key.as_ref())?).is_some())
     }
 
+    /// Removes a keyword from the `UnicodeExtensionList`, returning its value
+    /// with subtags joined by `-`.
+    ///
+    /// A keyword without a value returns `"true"`. Returns `None` if the key
+    /// is malformed or the keyword was not included in the `UnicodeExtensionList`.
+    pub(crate) fn take_keyword(&mut self, key: &[u8]) -> Option<String> {
+        let value = self.keywords.remove(&parse_key(key).ok()?)?;
+        if value.is_empty() {
+            return Some(TRUE_TYPE.to_string());
+        }
+        Some(join_types(&value))
+    }
+
+    /// Returns the value of a keyword, or `None` if it is not included.
+    ///
+    /// A value made of multiple subtags is joined with `-`, which is the only
+    /// case requiring an allocation, and a keyword without a value returns
+    /// `"true"`.
+    pub(crate) fn keyword_value(&self, key: &TinyStr4) -> Option<Cow<'_, str>> {
+        Some(match self.keywords.get(key)?.as_slice() {
+            [] => Cow::Borrowed("true"),
+            [value] => Cow::Borrowed(value.as_str()),
+            value => Cow::Owned(join_types(value)),
+        })
+    }
+
+    /// Returns `true` if the keyword is included in the `UnicodeExtensionList`
+    /// without a value, which stands for `"true"`.
+    ///
+    /// Returns `false` if the key is malformed, the keyword is not included,
+    /// or it has any other value.
+    pub(crate) fn keyword_is_true(&self, key: &[u8]) -> bool {
+        parse_key(key)
+            .ok()
+            .and_then(|key| self.keywords.get(&key))
+            .is_some_and(|value| value.is_empty())
+    }
+
     /// Clears all keywords from the `UnicodeExtensionList`.
     ///
     /// # Examples
@@ -336,6 +456,19 @@ impl UnicodeExtensionList {
         self.attributes.clear();
     }
 
+    /// Sets the keywords of `other`, replacing the values of existing keys,
+    /// and adds its attributes.
+    pub(crate) fn merge_from(&mut self, other: &Self) {
+        for (key, value) in &other.keywords {
+            self.keywords.insert(*key, value.clone());
+        }
+        for attribute in &other.attributes {
+            if let Err(idx) = self.attributes.binary_search(attribute) {
+                self.attributes.insert(idx, *attribute);
+            }
+        }
+    }
+
     pub(crate) fn try_from_iter<'a>(
         iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
     ) -> Result<Self, ParserError> {
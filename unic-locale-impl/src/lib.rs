@@ -1,9 +1,15 @@
 pub(crate) mod errors;
 pub mod extensions;
 pub mod parser;
+mod posix;
+#[cfg(feature = "serde")]
+mod serde;
+mod windows;
 
 use errors::LocaleError;
 pub use extensions::{ExtensionType, ExtensionsMap};
+use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::str::FromStr;
 pub use unic_langid_impl::CharacterDirection;
 pub use unic_langid_impl::{subtags, LanguageIdentifier};
@@ -63,6 +69,26 @@ pub use unic_langid_impl::{subtags, LanguageIdentifier};
 /// assert_eq!(loc.id.region, Some("US".parse().unwrap()));
 /// assert_eq!(loc.id.variants().collect::<Vec<_>>(), &["valencia"]);
 /// ```
+///
+/// # Equality
+///
+/// Extensions are stored in their canonical order, so two `Locale`s which only
+/// differ in the order of their extensions, Unicode keywords, attributes or
+/// transform fields compare equal. The order of private use subtags is significant.
+///
+/// Since parsing also normalizes casing, equal `Locale`s always produce the same
+/// `Hash`, which makes them suitable as keys in a `HashMap`.
+///
+/// ```
+/// use unic_locale_impl::Locale;
+///
+/// let loc1: Locale = "en-US-u-ca-gregory-nu-latn".parse()
+///     .expect("Failed to parse.");
+/// let loc2: Locale = "en-US-u-nu-latn-ca-gregory".parse()
+///     .expect("Failed to parse.");
+///
+/// assert_eq!(loc1, loc2);
+/// ```
 #[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
 pub struct Locale {
     pub id: LanguageIdentifier,
@@ -78,9 +104,12 @@ type PartsTuple = (
 );
 
 impl Locale {
-    /// A constructor which takes a utf8 slice, parses it and
+    /// A constructor which takes a byte slice, parses it and
     /// produces a well-formed `Locale`.
     ///
+    /// The bytes don't have to be validated as UTF-8 first. Only ASCII is
+    /// valid in BCP 47 subtags, so any other byte results in an error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -91,6 +120,19 @@ impl Locale {
     ///
     /// assert_eq!(loc.to_string(), "en-US-u-hc-h12");
     /// ```
+    ///
+    /// As with `LanguageIdentifier`, BCP 47 grandfathered tags are replaced
+    /// with their preferred values. They only match the whole input, so a
+    /// grandfathered tag followed by extensions is parsed like any other input.
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_bytes(b"zh-guoyu")
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "cmn");
+    /// ```
     pub fn from_bytes(v: &[u8]) -> Result<Self, LocaleError> {
         Ok(parser::parse_locale(v)?)
     }
@@ -204,6 +246,379 @@ impl Locale {
         }
         self.id.matches(&other.id, self_as_range, other_as_range)
     }
+
+    /// Returns `true` if the language, script, region and variants of the
+    /// `Locale` are equal to those of `other`, ignoring all extensions.
+    ///
+    /// `other` may be a `LanguageIdentifier` or another `Locale`. `Locale`
+    /// does not implement `PartialEq<LanguageIdentifier>`, as that would not be
+    /// transitive with the extension-aware equality between `Locale`s; use this
+    /// method, or compare [`Locale::as_langid`], instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "en-US-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "en-US-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    /// let langid: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.matches_langid(&langid), true);
+    /// assert_eq!(loc1.matches_langid(&loc2), true);
+    /// assert_ne!(loc1, loc2);
+    /// ```
+    pub fn matches_langid<L: AsRef<LanguageIdentifier>>(&self, other: &L) -> bool {
+        self.id == *other.as_ref()
+    }
+
+    /// Resets the `Locale` to `und`, clearing the language identifier
+    /// and removing all extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-u-ca-gregory-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.clear();
+    ///
+    /// assert_eq!(loc.to_string(), "und");
+    /// ```
+    pub fn clear(&mut self) {
+        self.id.clear();
+        self.extensions = ExtensionsMap::default();
+    }
+
+    /// Returns `true` if the `Locale` has any extensions, in which case its
+    /// string representation differs from the one of its `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "en-US-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.has_extensions(), true);
+    /// assert_eq!(loc2.has_extensions(), false);
+    /// ```
+    pub fn has_extensions(&self) -> bool {
+        !self.extensions.is_empty()
+    }
+
+    /// Consumes the `Locale` and returns its `LanguageIdentifier`,
+    /// dropping all extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-ca-buddhist-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.into_langid().to_string(), "en-US");
+    /// ```
+    pub fn into_langid(self) -> LanguageIdentifier {
+        self.id
+    }
+
+    /// Returns a reference to the `LanguageIdentifier` of the `Locale`,
+    /// ignoring all extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-ca-buddhist-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.as_langid().to_string(), "en-US");
+    /// ```
+    pub fn as_langid(&self) -> &LanguageIdentifier {
+        &self.id
+    }
+
+    /// Returns character direction of the `Locale`.
+    ///
+    /// An explicit script subtag takes precedence over the language, so
+    /// `az-Arab` is right-to-left even though `az` is left-to-right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Locale, CharacterDirection};
+    ///
+    /// let loc1: Locale = "pa-Arab-PK-u-nu-arabext".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "ar-Latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.character_direction(), CharacterDirection::RTL);
+    /// assert_eq!(loc2.character_direction(), CharacterDirection::LTR);
+    /// ```
+    #[cfg(feature = "direction")]
+    pub fn character_direction(&self) -> CharacterDirection {
+        self.id.character_direction()
+    }
+
+    /// Returns the value of the `ca` (calendar) Unicode extension keyword,
+    /// or `None` if it is not set.
+    ///
+    /// As in `remove_unicode_keyword`, a value made of multiple subtags, such
+    /// as `islamic-civil`, is joined with `-`, and a keyword without a value
+    /// returns `"true"`. Only a joined value is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "th-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.unicode_calendar().as_deref(), Some("buddhist"));
+    ///
+    /// let loc: Locale = "ar-u-ca-islamic-civil".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.unicode_calendar().as_deref(), Some("islamic-civil"));
+    /// ```
+    pub fn unicode_calendar(&self) -> Option<Cow<'_, str>> {
+        self.extensions
+            .unicode
+            .keyword_value(&extensions::unicode::CALENDAR_KEY)
+    }
+
+    /// Sets the value of the `ca` (calendar) Unicode extension keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.set_unicode_calendar(&["gregory"])
+    ///     .expect("Setting calendar failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-u-ca-gregory");
+    /// ```
+    pub fn set_unicode_calendar<S: AsRef<[u8]>>(&mut self, value: &[S]) -> Result<(), LocaleError> {
+        self.extensions
+            .unicode
+            .set_keyword_by_key(extensions::unicode::CALENDAR_KEY, value)
+    }
+
+    /// Returns the value of the `nu` (numbering system) Unicode extension
+    /// keyword, or `None` if it is not set.
+    ///
+    /// Values are joined like in `unicode_calendar`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "th-u-nu-thai".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.unicode_numbering_system().as_deref(), Some("thai"));
+    /// assert_eq!(loc.unicode_calendar(), None);
+    /// ```
+    pub fn unicode_numbering_system(&self) -> Option<Cow<'_, str>> {
+        self.extensions
+            .unicode
+            .keyword_value(&extensions::unicode::NUMBERING_SYSTEM_KEY)
+    }
+
+    /// Sets the value of the `nu` (numbering system) Unicode extension keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "ar-EG".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.set_unicode_numbering_system(&["latn"])
+    ///     .expect("Setting numbering system failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "ar-EG-u-nu-latn");
+    /// ```
+    pub fn set_unicode_numbering_system<S: AsRef<[u8]>>(
+        &mut self,
+        value: &[S],
+    ) -> Result<(), LocaleError> {
+        self.extensions
+            .unicode
+            .set_keyword_by_key(extensions::unicode::NUMBERING_SYSTEM_KEY, value)
+    }
+
+    /// Removes a Unicode extension keyword, returning its value if it was set.
+    ///
+    /// The value of a keyword made of multiple subtags is joined with `-`,
+    /// and a keyword without a value returns `"true"`. A malformed key
+    /// returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-u-rg-gbzzzz-ca-islamic-civil".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.remove_unicode_keyword("rg"), Some("gbzzzz".to_string()));
+    /// assert_eq!(loc.remove_unicode_keyword("rg"), None);
+    /// assert_eq!(loc.to_string(), "en-US-u-ca-islamic-civil");
+    ///
+    /// assert_eq!(loc.remove_unicode_keyword("ca"), Some("islamic-civil".to_string()));
+    /// assert_eq!(loc.to_string(), "en-US");
+    /// ```
+    pub fn remove_unicode_keyword<S: AsRef<[u8]>>(&mut self, key: S) -> Option<String> {
+        self.extensions.unicode.take_keyword(key.as_ref())
+    }
+
+    /// Returns the value of a boolean Unicode extension keyword, such as `kn`
+    /// (numeric collation).
+    ///
+    /// A keyword without a value, or with the value `true`, is `true`. The
+    /// keyword is `false` if it is not set, has any other value, or the key
+    /// is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-u-kn-kf-false".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.unicode_keyword_bool("kn"), true);
+    /// assert_eq!(loc.unicode_keyword_bool("kf"), false);
+    /// assert_eq!(loc.unicode_keyword_bool("ca"), false);
+    /// assert_eq!(loc.to_string(), "en-u-kf-false-kn");
+    /// ```
+    pub fn unicode_keyword_bool<S: AsRef<[u8]>>(&self, key: S) -> bool {
+        self.extensions.unicode.keyword_is_true(key.as_ref())
+    }
+
+    /// Parses a fragment of extensions, such as `u-ca-gregory-nu-arab`, and
+    /// merges it into the extensions of the `Locale`.
+    ///
+    /// A leading separator is allowed. Unicode keywords and tfields replace
+    /// the values of existing keys, and a transform source language replaces
+    /// the existing one. Unicode attributes and private use tags are added
+    /// to the existing ones, and other extensions replace the existing
+    /// extension with the same singleton.
+    ///
+    /// If the fragment is malformed, an error is returned and the `Locale`
+    /// is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "ar-EG-u-ca-islamic-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.apply_extensions_str("u-ca-gregory-nu-arab-x-bar")
+    ///     .expect("Applying extensions failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "ar-EG-u-ca-gregory-nu-arab-x-foo-bar");
+    /// assert!(loc.apply_extensions_str("ca-gregory").is_err());
+    /// ```
+    pub fn apply_extensions_str(&mut self, ext: &str) -> Result<(), LocaleError> {
+        let ext = ext.strip_prefix(['-', '_']).unwrap_or(ext);
+        let extensions = ExtensionsMap::from_bytes(ext.as_bytes())?;
+        self.extensions.merge_from(&extensions);
+        Ok(())
+    }
+
+    /// Merges the extensions of `other` into the extensions of the `Locale`,
+    /// giving precedence to `other`. The subtags of `other` are ignored.
+    ///
+    /// * Unicode keywords of `other` replace the values of the same keys.
+    /// * Unicode attributes and private use tags are unioned, keeping the
+    ///   existing ones first.
+    /// * The transform source language of `other`, if any, replaces the
+    ///   existing one, and its tfields replace the values of the same tkeys.
+    /// * Other extensions of `other` replace the extension with the same
+    ///   singleton.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-u-ca-buddhist-hc-h12-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    /// let prefs: Locale = "de-u-hc-h23-x-bar".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.merge_extensions_from(&prefs);
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-u-ca-buddhist-hc-h23-x-foo-bar");
+    /// ```
+    pub fn merge_extensions_from(&mut self, other: &Locale) {
+        self.extensions.merge_from(&other.extensions);
+    }
+
+    /// Sets the source language of the transform extension (its tlang),
+    /// replacing any previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{LanguageIdentifier, Locale};
+    ///
+    /// let mut loc: Locale = "de".parse()
+    ///     .expect("Parsing failed.");
+    /// let source: LanguageIdentifier = "en".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.set_transform_source(source);
+    ///
+    /// assert_eq!(loc.to_string(), "de-t-en");
+    /// ```
+    pub fn set_transform_source(&mut self, langid: LanguageIdentifier) {
+        self.extensions.transform.tlang = Some(langid);
+    }
+
+    /// Removes the transform extension, including its source language and
+    /// all tfields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "de-t-en-h0-hybrid-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.clear_transform();
+    ///
+    /// assert_eq!(loc.to_string(), "de-u-ca-buddhist");
+    /// ```
+    pub fn clear_transform(&mut self) {
+        self.extensions.transform.clear_tlang();
+        self.extensions.transform.clear_tfields();
+    }
 }
 
 impl FromStr for Locale {
@@ -214,6 +629,22 @@ impl FromStr for Locale {
     }
 }
 
+impl TryFrom<&str> for Locale {
+    type Error = LocaleError;
+
+    fn try_from(source: &str) -> Result<Self, Self::Error> {
+        source.parse()
+    }
+}
+
+impl TryFrom<String> for Locale {
+    type Error = LocaleError;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        source.parse()
+    }
+}
+
 impl From<LanguageIdentifier> for Locale {
     fn from(id: LanguageIdentifier) -> Self {
         Locale {
@@ -223,12 +654,33 @@ impl From<LanguageIdentifier> for Locale {
     }
 }
 
-impl Into<LanguageIdentifier> for Locale {
-    fn into(self) -> LanguageIdentifier {
-        self.id
+impl From<Locale> for LanguageIdentifier {
+    fn from(loc: Locale) -> Self {
+        loc.id
     }
 }
 
+/// Allows functions bounded on `AsRef<LanguageIdentifier>` to accept both
+/// a `LanguageIdentifier` and a `Locale`, ignoring the extensions of the latter.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_locale_impl::Locale;
+///
+/// fn region(l: impl AsRef<LanguageIdentifier>) -> Option<String> {
+///     l.as_ref().region.map(|r| r.to_string())
+/// }
+///
+/// let loc: Locale = "ar-EG-u-nu-latn".parse()
+///     .expect("Parsing failed.");
+/// let langid: LanguageIdentifier = "en-US".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(region(&loc), Some("EG".to_string()));
+/// assert_eq!(region(langid), Some("US".to_string()));
+/// ```
 impl AsRef<LanguageIdentifier> for Locale {
     fn as_ref(&self) -> &LanguageIdentifier {
         &self.id
@@ -3,9 +3,16 @@ pub mod errors;
 pub use self::errors::ParserError;
 use super::extensions::ExtensionsMap;
 use super::Locale;
-use unic_langid_impl::LanguageIdentifier;
+use unic_langid_impl::{parser, LanguageIdentifier};
 
 pub fn parse_locale<S: AsRef<[u8]>>(t: S) -> Result<Locale, ParserError> {
+    if let Some(id) = parser::parse_grandfathered(t.as_ref()) {
+        return Ok(Locale {
+            id,
+            extensions: ExtensionsMap::default(),
+        });
+    }
+
     let mut iter = t.as_ref().split(|c| *c == b'-' || *c == b'_').peekable();
 
     let id = LanguageIdentifier::try_from_iter(&mut iter, true)
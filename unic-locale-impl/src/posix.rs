@@ -0,0 +1,147 @@
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+use crate::subtags::{Language, Region, Script};
+use crate::{LanguageIdentifier, Locale};
+
+use tinystr::TinyStr4;
+
+const CURRENCY_KEY: TinyStr4 = unsafe { TinyStr4::new_unchecked(30_051u32) }; // "cu"
+const VARIANT_KEY: TinyStr4 = unsafe { TinyStr4::new_unchecked(24_950u32) }; // "va"
+
+const LATIN_SCRIPT: Script = unsafe { Script::from_raw_unchecked(1_853_120_844u32) }; // "Latn"
+const CYRILLIC_SCRIPT: Script = unsafe { Script::from_raw_unchecked(1_819_441_475u32) }; // "Cyrl"
+
+const POSIX_LANGUAGE: Language = unsafe { Language::from_raw_unchecked(28_261u64) }; // "en"
+const POSIX_REGION: Region = unsafe { Region::from_raw_unchecked(21_333u32) }; // "US"
+
+impl Locale {
+    /// A constructor which takes a POSIX locale name such as `en_US.UTF-8@euro`
+    /// and produces a well-formed `Locale`.
+    ///
+    /// The codeset is ignored, while the `@euro`, `@latin` and `@cyrillic`
+    /// modifiers are mapped to the `cu-eur` Unicode extension keyword, the
+    /// `Latn` script and the `Cyrl` script respectively. The `C` and `POSIX`
+    /// locales map to `en-US-u-va-posix`.
+    ///
+    /// Any other modifier results in an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_posix("de_AT.UTF-8@euro")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "de-AT-u-cu-eur");
+    ///
+    /// let loc = Locale::from_posix("sr_RS@latin")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "sr-Latn-RS");
+    ///
+    /// assert!(Locale::from_posix("de_DE@unknown").is_err());
+    /// ```
+    pub fn from_posix(input: &str) -> Result<Self, LocaleError> {
+        let (name, modifier) = match input.find('@') {
+            Some(idx) => (&input[..idx], Some(&input[idx + 1..])),
+            None => (input, None),
+        };
+        let name = match name.find('.') {
+            Some(idx) => &name[..idx],
+            None => name,
+        };
+
+        if name == "C" || name == "POSIX" {
+            if modifier.is_some() {
+                return Err(ParserError::InvalidSubtag.into());
+            }
+            let mut loc: Locale =
+                LanguageIdentifier::from_parts(POSIX_LANGUAGE, None, Some(POSIX_REGION), &[])
+                    .into();
+            loc.extensions
+                .unicode
+                .set_keyword_by_key(VARIANT_KEY, &["posix"])?;
+            return Ok(loc);
+        }
+
+        let mut loc: Locale = LanguageIdentifier::from_bytes(name.as_bytes())?.into();
+
+        match modifier {
+            None => {}
+            Some("euro") => {
+                loc.extensions
+                    .unicode
+                    .set_keyword_by_key(CURRENCY_KEY, &["eur"])?;
+            }
+            Some("latin") => loc.id.script = Some(LATIN_SCRIPT),
+            Some("cyrillic") => loc.id.script = Some(CYRILLIC_SCRIPT),
+            Some(_) => return Err(ParserError::InvalidSubtag.into()),
+        }
+
+        Ok(loc)
+    }
+
+    /// Serializes the `Locale` into a POSIX locale name.
+    ///
+    /// This is the inverse of `from_posix`. Only the language and region
+    /// subtags, the `Latn` and `Cyrl` scripts and the `cu-eur` keyword are
+    /// represented, other subtags and extensions are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "de-AT-u-cu-eur".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_posix(), "de_AT@euro");
+    ///
+    /// let loc: Locale = "sr-Cyrl-RS".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_posix(), "sr_RS@cyrillic");
+    /// ```
+    pub fn to_posix(&self) -> String {
+        if self.is_posix_locale() {
+            return "C".to_string();
+        }
+
+        let mut result = self.id.language.as_str().to_string();
+        if let Some(region) = self.id.region {
+            result.push('_');
+            result.push_str(region.as_str());
+        }
+
+        if self.id.script == Some(LATIN_SCRIPT) {
+            result.push_str("@latin");
+        } else if self.id.script == Some(CYRILLIC_SCRIPT) {
+            result.push_str("@cyrillic");
+        } else if self
+            .extensions
+            .unicode
+            .keyword_by_key(&CURRENCY_KEY)
+            .eq(std::iter::once("eur"))
+        {
+            result.push_str("@euro");
+        }
+
+        result
+    }
+
+    /// Returns `true` if the `Locale` is exactly `en-US-u-va-posix`, which
+    /// stands for the `C` and `POSIX` locales.
+    fn is_posix_locale(&self) -> bool {
+        self.id.language == POSIX_LANGUAGE
+            && self.id.script.is_none()
+            && self.id.region == Some(POSIX_REGION)
+            && self.id.variants().len() == 0
+            && self.extensions.unicode.attributes().len() == 0
+            && self.extensions.unicode.keywords_len() == 1
+            && self
+                .extensions
+                .unicode
+                .keyword_by_key(&VARIANT_KEY)
+                .eq(std::iter::once("posix"))
+            && self.extensions.transform.is_empty()
+            && self.extensions.other.is_empty()
+            && self.extensions.private.is_empty()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::Locale;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LocaleVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for LocaleVisitor {
+            type Value = Locale;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "A valid Unicode Locale Identifier")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                s.parse::<Locale>().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_string(LocaleVisitor)
+    }
+}
+
+#[test]
+fn serialize() -> Result<(), Box<dyn std::error::Error>> {
+    let fr = serde_json::json!("fr".parse::<Locale>()?);
+    let en = serde_json::json!("en-US-u-ca-gregory-x-foo".parse::<Locale>()?);
+
+    assert_eq!(serde_json::to_string(&fr)?, r#""fr""#);
+    assert_eq!(serde_json::to_string(&en)?, r#""en-US-u-ca-gregory-x-foo""#);
+
+    Ok(())
+}
+
+#[test]
+fn deserialize() -> Result<(), Box<dyn std::error::Error>> {
+    let fr = serde_json::from_str::<Locale>(r#""fr""#)?;
+    let en = serde_json::from_str::<Locale>(r#""en-US-t-es-AR-u-hc-h12""#)?;
+
+    assert_eq!(fr, "fr".parse::<Locale>()?);
+    assert_eq!(en, "en-US-t-es-AR-u-hc-h12".parse::<Locale>()?);
+
+    assert!(serde_json::from_str::<Locale>(r#""e1-US-u-ca-gregory""#).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+use crate::{LanguageIdentifier, Locale};
+
+use tinystr::TinyStr4;
+
+const COLLATION_KEY: TinyStr4 = unsafe { TinyStr4::new_unchecked(28_515u32) }; // "co"
+
+/// The name Windows uses for the invariant locale.
+const INVARIANT_NAME: &str = "x-IV";
+
+/// Windows sort order suffixes and their `co` Unicode extension keyword values.
+static SORT_ORDERS: &[(&str, &str)] = &[
+    ("phoneb", "phonebk"),
+    ("pronun", "zhuyin"),
+    ("radstr", "unihan"),
+    ("stroke", "stroke"),
+    ("tradnl", "trad"),
+];
+
+/// Legacy Windows names and their modern equivalents.
+static ALIASES: &[(&str, &str)] = &[("zh-CHS", "zh-Hans"), ("zh-CHT", "zh-Hant")];
+
+impl Locale {
+    /// A constructor which takes a Windows locale name such as `de-DE_phoneb`
+    /// and produces a well-formed `Locale`.
+    ///
+    /// The invariant locale (`x-IV` or an empty name) maps to `und`, legacy
+    /// names such as `zh-CHS` map to their modern equivalents and sort order
+    /// suffixes map to the `co` Unicode extension keyword.
+    ///
+    /// An unknown sort order suffix results in an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_windows_name("de-DE_phoneb")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "de-DE-u-co-phonebk");
+    ///
+    /// let loc = Locale::from_windows_name("zh-CHT")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "zh-Hant");
+    ///
+    /// let loc = Locale::from_windows_name("x-IV")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "und");
+    /// ```
+    pub fn from_windows_name(input: &str) -> Result<Self, LocaleError> {
+        if input.is_empty() || input.eq_ignore_ascii_case(INVARIANT_NAME) {
+            return Ok(Self::default());
+        }
+
+        let (name, sort_order) = match input.find('_') {
+            Some(idx) => (&input[..idx], Some(&input[idx + 1..])),
+            None => (input, None),
+        };
+
+        let name = ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+            .map_or(name, |(_, modern)| modern);
+
+        let mut loc: Locale = LanguageIdentifier::from_bytes(name.as_bytes())?.into();
+
+        if let Some(sort_order) = sort_order {
+            let (_, collation) = SORT_ORDERS
+                .iter()
+                .find(|(suffix, _)| suffix.eq_ignore_ascii_case(sort_order))
+                .ok_or(ParserError::InvalidSubtag)?;
+            loc.extensions
+                .unicode
+                .set_keyword_by_key(COLLATION_KEY, &[collation])?;
+        }
+
+        Ok(loc)
+    }
+
+    /// Serializes the `Locale` into a Windows locale name.
+    ///
+    /// This is the inverse of `from_windows_name`. Only the `co` Unicode extension
+    /// keyword values that have a Windows sort order are represented, other
+    /// extensions are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "es-ES-u-co-trad".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_windows_name(), "es-ES_tradnl");
+    ///
+    /// let loc: Locale = "und".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_windows_name(), "x-IV");
+    /// ```
+    pub fn to_windows_name(&self) -> String {
+        if self.id.is_empty() {
+            return INVARIANT_NAME.to_string();
+        }
+
+        let mut result = self.id.to_string();
+
+        let mut collation = self.extensions.unicode.keyword_by_key(&COLLATION_KEY);
+        if let (1, Some(collation)) = (collation.len(), collation.next()) {
+            if let Some((suffix, _)) = SORT_ORDERS.iter().find(|(_, co)| *co == collation) {
+                result.push('_');
+                result.push_str(suffix);
+            }
+        }
+
+        result
+    }
+}
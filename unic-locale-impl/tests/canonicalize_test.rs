@@ -1,4 +1,4 @@
-use unic_locale_impl::canonicalize;
+use unic_locale_impl::{canonicalize, Locale};
 
 fn assert_canonicalize(input: &str, output: &str) {
     assert_eq!(&canonicalize(input).unwrap(), output);
@@ -32,3 +32,72 @@ fn test_canonicalize_locale() {
     assert_canonicalize("de-u-kn-true", "de-u-kn");
     assert_canonicalize("fr-t-t0-windows-True", "fr-t-t0-windows");
 }
+
+#[test]
+fn test_canonicalize_tfields_followed_by_extension() {
+    assert_canonicalize("en-t-de-h0-hybrid-x-foo", "en-t-de-h0-hybrid-x-foo");
+    assert_canonicalize("en-t-h0-hybrid-u-ca-gregory", "en-t-h0-hybrid-u-ca-gregory");
+}
+
+#[test]
+fn test_canonicalize_errors() {
+    assert!(canonicalize("en-US-u-c").is_err());
+    assert!(canonicalize("en-a").is_err());
+    assert!(canonicalize("en-u-ca-toolongvalue").is_err());
+    assert!(canonicalize("en-t-h0-hybrid-toolongvalue").is_err());
+}
+
+#[test]
+fn test_canonicalize_other_extensions() {
+    assert_canonicalize("EN-B-Bar-a-FOO", "en-a-foo-b-bar");
+}
+
+#[test]
+fn test_canonicalize_is_idempotent() {
+    let languages = ["en", "EN", "und", "zh", "sgn"];
+    let scripts = ["", "-Latn", "-hans"];
+    let regions = ["", "-US", "-419"];
+    let variants = ["", "-macos", "-POSIX-1996"];
+    let extensions = [
+        "",
+        "-u-ca-buddhist-nu-thai",
+        "-U-KN-true",
+        "-u-foo-bar-kk",
+        "-t-es-AR-h0-hybrid",
+        "-T-K0-Dvorak-true",
+        "-t-de-latn-1996",
+        "-a-foo",
+        "-b-bar-a-foo-bar",
+        "-x-Priv-foo",
+        "-u-ca-gregory-t-de-x-foo",
+        "-x-foo-t-es-u-ca-buddhist",
+        "-z-zzz-t-h0-hybrid-u-kn-b-bbb",
+    ];
+
+    let mut count = 0;
+    for language in &languages {
+        for script in &scripts {
+            for region in &regions {
+                for variant in &variants {
+                    for extension in &extensions {
+                        let input =
+                            format!("{}{}{}{}{}", language, script, region, variant, extension);
+                        for input in &[input.clone(), input.replace('-', "_")] {
+                            let once = canonicalize(input).unwrap();
+                            let twice = canonicalize(&once).unwrap();
+                            assert_eq!(once, twice, "{}", input);
+                            assert_eq!(
+                                once.parse::<Locale>().unwrap(),
+                                input.parse::<Locale>().unwrap(),
+                                "{}",
+                                input
+                            );
+                            count += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(count, 2 * 5 * 3 * 3 * 3 * 13);
+}
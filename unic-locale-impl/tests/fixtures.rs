@@ -1,18 +1,62 @@
-use std::collections::HashMap;
 use std::convert::TryInto;
 use std::error::Error;
+use std::fmt;
 use std::fs::File;
+use std::marker::PhantomData;
 use std::path::Path;
 
 use unic_langid_impl::LanguageIdentifier;
 use unic_locale_impl::{ExtensionType, ExtensionsMap, Locale};
 
-use serde::{Deserialize, Serialize};
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A JSON object which keeps its entries in document order, as the order of
+/// private use subtags is significant.
+#[derive(Debug)]
+struct OrderedMap<V>(Vec<(String, V)>);
+
+impl<V: Serialize> Serialize for OrderedMap<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for OrderedMap<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedMapVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: Deserialize<'de>> Visitor<'de> for OrderedMapVisitor<V> {
+            type Value = OrderedMap<V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut entries = vec![];
+                while let Some(entry) = access.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(OrderedMap(entries))
+            }
+        }
+
+        deserializer.deserialize_map(OrderedMapVisitor(PhantomData))
+    }
+}
+
+type ExtensionsFixture = OrderedMap<OrderedMap<String>>;
 
 #[derive(Serialize, Deserialize)]
 struct LocaleTestInputData {
     string: String,
-    extensions: Option<HashMap<String, HashMap<String, String>>>,
+    extensions: Option<ExtensionsFixture>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -22,7 +66,7 @@ struct LocaleTestOutputObject {
     region: Option<String>,
     #[serde(default)]
     variants: Vec<String>,
-    extensions: Option<HashMap<String, HashMap<String, String>>>,
+    extensions: Option<ExtensionsFixture>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -44,14 +88,14 @@ fn read_locale_testsets<P: AsRef<Path>>(path: P) -> Result<Vec<LocaleTestSet>, B
     Ok(sets)
 }
 
-fn create_extensions_map(map: HashMap<String, HashMap<String, String>>) -> ExtensionsMap {
+fn create_extensions_map(map: ExtensionsFixture) -> ExtensionsMap {
     let mut result = ExtensionsMap::default();
-    for (key, map) in map {
+    for (key, map) in map.0 {
         let t: ExtensionType = ExtensionType::from_byte(key.chars().nth(0).unwrap() as u8)
             .expect("Failed to format extension type.");
         match t {
             ExtensionType::Unicode => {
-                for (key, value) in map {
+                for (key, value) in map.0 {
                     result
                         .unicode
                         .set_keyword(&key, &[&value])
@@ -59,7 +103,7 @@ fn create_extensions_map(map: HashMap<String, HashMap<String, String>>) -> Exten
                 }
             }
             ExtensionType::Transform => {
-                if let Some(tfield) = map.get("tlang") {
+                if let Some((_, tfield)) = map.0.iter().find(|(key, _)| key == "tlang") {
                     let tlang: LanguageIdentifier =
                         tfield.parse().expect("Parsing language identifier failed.");
                     result
@@ -69,7 +113,7 @@ fn create_extensions_map(map: HashMap<String, HashMap<String, String>>) -> Exten
                 }
             }
             ExtensionType::Private => {
-                for (key, _) in map {
+                for (key, _) in map.0 {
                     result
                         .private
                         .add_tag(&key)
@@ -91,8 +135,8 @@ fn test_locale_fixtures(path: &str) {
         let mut locale: Locale = s.parse().expect("Parsing failed.");
 
         if let Some(extensions) = test.input.extensions {
-            for (_, map) in extensions {
-                for (key, value) in map {
+            for (_, map) in extensions.0 {
+                for (key, value) in map.0 {
                     locale
                         .extensions
                         .unicode
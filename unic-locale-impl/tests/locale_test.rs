@@ -1,6 +1,8 @@
 use unic_langid_impl::LanguageIdentifier;
 use unic_locale_impl::parser::parse_locale;
-use unic_locale_impl::{CharacterDirection, ExtensionsMap, Locale};
+#[cfg(feature = "direction")]
+use unic_locale_impl::CharacterDirection;
+use unic_locale_impl::{ExtensionsMap, Locale};
 
 fn assert_locale_extensions(loc: &Locale, extensions: &ExtensionsMap) {
     assert_eq!(&loc.extensions, extensions);
@@ -185,6 +187,31 @@ fn test_set_fields() {
     assert_eq!(&loc.to_string(), "und");
 }
 
+#[test]
+fn test_set_extensions_in_place() {
+    let mut loc: Locale = "en-US".parse().unwrap();
+
+    loc.extensions
+        .unicode
+        .set_keyword("ca", &["gregory"])
+        .unwrap();
+    assert_eq!(&loc.to_string(), "en-US-u-ca-gregory");
+    loc.extensions.unicode.set_keyword("nu", &["latn"]).unwrap();
+    assert_eq!(&loc.to_string(), "en-US-u-ca-gregory-nu-latn");
+
+    // Setting an existing keyword overwrites its value.
+    loc.extensions
+        .unicode
+        .set_keyword("ca", &["buddhist"])
+        .unwrap();
+    assert_eq!(&loc.to_string(), "en-US-u-ca-buddhist-nu-latn");
+
+    assert_eq!(
+        loc,
+        "en-US-u-ca-buddhist-nu-latn".parse::<Locale>().unwrap()
+    );
+}
+
 #[cfg(feature = "likelysubtags")]
 #[test]
 fn test_likelysubtags() {
@@ -206,6 +233,7 @@ fn test_likelysubtags() {
 }
 
 #[test]
+#[cfg(feature = "direction")]
 fn test_character_direction() {
     let loc_en: Locale = "en-u-hc-h12".parse().unwrap();
     assert_eq!(loc_en.id.character_direction(), CharacterDirection::LTR);
@@ -214,6 +242,31 @@ fn test_character_direction() {
     assert_eq!(loc_ar.id.character_direction(), CharacterDirection::RTL);
 }
 
+#[test]
+#[cfg(feature = "direction")]
+fn test_locale_character_direction() {
+    let tests = &[
+        ("az-Arab", CharacterDirection::RTL),
+        ("pa-Arab-PK", CharacterDirection::RTL),
+        ("en-Hebr-u-ca-hebrew", CharacterDirection::RTL),
+        ("dv-Thaa", CharacterDirection::RTL),
+        ("syr-Syrc", CharacterDirection::RTL),
+        ("man-Nkoo", CharacterDirection::RTL),
+        ("ff-Adlm-GN", CharacterDirection::RTL),
+        ("ar-Latn", CharacterDirection::LTR),
+        ("he-Latn-IL-x-foo", CharacterDirection::LTR),
+        ("az", CharacterDirection::LTR),
+        ("pa-PK", CharacterDirection::LTR),
+        ("ar-u-nu-latn", CharacterDirection::RTL),
+        ("fa-IR", CharacterDirection::RTL),
+    ];
+
+    for (input, direction) in tests {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.character_direction(), *direction, "{}", input);
+    }
+}
+
 #[test]
 fn test_unicode_attributes_ordering() {
     let mut loc: Locale = "en-u-foo-bar".parse().unwrap();
@@ -225,3 +278,600 @@ fn test_unicode_attributes_ordering() {
         .expect("Can't set attribute");
     assert_eq!(&loc.to_string(), "en-u-bar-baz-foo");
 }
+
+#[test]
+fn test_unicode_attributes_with_keywords() {
+    let loc: Locale = "en-US-u-foobar-ca-gregory".parse().unwrap();
+    assert_eq!(
+        loc.extensions.unicode.attributes().collect::<Vec<_>>(),
+        &["foobar"]
+    );
+    assert_eq!(
+        loc.extensions
+            .unicode
+            .keyword("ca")
+            .unwrap()
+            .collect::<Vec<_>>(),
+        &["gregory"]
+    );
+    assert_eq!(&loc.to_string(), "en-US-u-foobar-ca-gregory");
+
+    let loc: Locale = "en-u-foobar-ca-gregory".parse().unwrap();
+    assert_eq!(&loc.to_string(), "en-u-foobar-ca-gregory");
+
+    let loc: Locale = "en-u-zzz-aaa-nu-latn-ca-gregory".parse().unwrap();
+    assert_eq!(&loc.to_string(), "en-u-aaa-zzz-ca-gregory-nu-latn");
+}
+
+#[test]
+fn test_und_with_extensions() {
+    let loc: Locale = "und-u-ca-gregory".parse().unwrap();
+    assert!(loc.id.is_empty());
+    assert!(!loc.extensions.is_empty());
+    assert_eq!(&loc.to_string(), "und-u-ca-gregory");
+}
+
+#[test]
+fn test_unicode_calendar_and_numbering_system() {
+    let mut loc: Locale = "en-US".parse().unwrap();
+    assert_eq!(loc.unicode_calendar(), None);
+    assert_eq!(loc.unicode_numbering_system(), None);
+
+    loc.set_unicode_calendar(&["gregory"]).unwrap();
+    loc.set_unicode_numbering_system(&["latn"]).unwrap();
+    assert_eq!(loc.unicode_calendar().as_deref(), Some("gregory"));
+    assert_eq!(loc.unicode_numbering_system().as_deref(), Some("latn"));
+    assert_eq!(&loc.to_string(), "en-US-u-ca-gregory-nu-latn");
+
+    loc.set_unicode_calendar(&["ISLAMIC", "civil"]).unwrap();
+    assert_eq!(loc.unicode_calendar().as_deref(), Some("islamic-civil"));
+    assert_eq!(&loc.to_string(), "en-US-u-ca-islamic-civil-nu-latn");
+
+    assert!(loc.set_unicode_calendar(&["gr"]).is_err());
+    assert!(loc.set_unicode_numbering_system(&["toolongvalue"]).is_err());
+    assert_eq!(&loc.to_string(), "en-US-u-ca-islamic-civil-nu-latn");
+
+    let loc: Locale = "en-u-ca".parse().unwrap();
+    assert_eq!(loc.unicode_calendar().as_deref(), Some("true"));
+}
+
+#[test]
+fn test_transform_source() {
+    let loc: Locale = "en-t-de".parse().unwrap();
+    let de: LanguageIdentifier = "de".parse().unwrap();
+    assert_eq!(loc.extensions.transform.tlang(), Some(&de));
+    assert_eq!(loc.extensions.transform.tfield_keys().len(), 0);
+
+    let loc: Locale = "ja-t-it-Latn-h0-hybrid-k0-dvorak".parse().unwrap();
+    let it_latn: LanguageIdentifier = "it-Latn".parse().unwrap();
+    assert_eq!(loc.extensions.transform.tlang(), Some(&it_latn));
+    assert_eq!(
+        loc.extensions.transform.tfield_keys().collect::<Vec<_>>(),
+        &["h0", "k0"]
+    );
+    assert_eq!(
+        loc.extensions
+            .transform
+            .tfield("k0")
+            .unwrap()
+            .collect::<Vec<_>>(),
+        &["dvorak"]
+    );
+
+    // tlang is always serialized before tfields.
+    let mut loc: Locale = "en".parse().unwrap();
+    loc.extensions
+        .transform
+        .set_tfield("h0", &["hybrid"])
+        .unwrap();
+    loc.extensions.transform.set_tlang(de).unwrap();
+    assert_eq!(&loc.to_string(), "en-t-de-h0-hybrid");
+}
+
+#[test]
+fn test_private_use_order() {
+    let mut loc: Locale = "en-x-foo-bar-baz".parse().unwrap();
+    assert_eq!(
+        loc.extensions.private.tags().collect::<Vec<_>>(),
+        &["foo", "bar", "baz"]
+    );
+    assert_eq!(&loc.to_string(), "en-x-foo-bar-baz");
+
+    loc.extensions.private.add_tag("a1").unwrap();
+    assert_eq!(&loc.to_string(), "en-x-foo-bar-baz-a1");
+    assert!(loc.extensions.private.add_tag("toolongtag").is_err());
+    assert!(loc.extensions.private.add_tag("").is_err());
+
+    assert_eq!(loc.extensions.private.remove_tag("bar"), Ok(true));
+    assert_eq!(&loc.to_string(), "en-x-foo-baz-a1");
+
+    let loc: Locale = "en-x-b-a".parse().unwrap();
+    assert_ne!(loc, "en-x-a-b".parse::<Locale>().unwrap());
+}
+
+#[test]
+fn test_posix() {
+    let tests = &[
+        ("en_US.UTF-8", "en-US", "en_US"),
+        ("de_AT.ISO-8859-15@euro", "de-AT-u-cu-eur", "de_AT@euro"),
+        ("sr_RS@latin", "sr-Latn-RS", "sr_RS@latin"),
+        ("uz_UZ.UTF-8@cyrillic", "uz-Cyrl-UZ", "uz_UZ@cyrillic"),
+        ("pl", "pl", "pl"),
+        ("C", "en-US-u-va-posix", "C"),
+        ("POSIX.UTF-8", "en-US-u-va-posix", "C"),
+    ];
+
+    for (input, locale, posix) in tests {
+        let loc = Locale::from_posix(input).unwrap();
+        assert_eq!(&loc.to_string(), locale);
+        assert_eq!(&loc.to_posix(), posix);
+        assert_eq!(Locale::from_posix(posix).unwrap(), loc);
+    }
+
+    assert!(Locale::from_posix("de_DE@unknown").is_err());
+    assert!(Locale::from_posix("C@euro").is_err());
+    assert!(Locale::from_posix("e1_US.UTF-8").is_err());
+
+    for (input, posix) in &[
+        ("en-US-u-va-posix-ca-gregory", "en_US"),
+        ("en-US-u-attr-va-posix", "en_US"),
+        ("en-US-posix-u-va-posix", "en_US"),
+        ("en-US-u-va-posix-x-foo", "en_US"),
+        ("en-Latn-US-u-va-posix", "en_US@latin"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(&loc.to_posix(), posix, "{}", input);
+    }
+}
+
+#[test]
+fn test_windows_name() {
+    let tests = &[
+        ("en-US", "en-US", "en-US"),
+        ("sr-Latn-RS", "sr-Latn-RS", "sr-Latn-RS"),
+        ("de-DE_phoneb", "de-DE-u-co-phonebk", "de-DE_phoneb"),
+        ("es-ES_tradnl", "es-ES-u-co-trad", "es-ES_tradnl"),
+        ("zh-TW_pronun", "zh-TW-u-co-zhuyin", "zh-TW_pronun"),
+        ("ja-JP_radstr", "ja-JP-u-co-unihan", "ja-JP_radstr"),
+        ("zh-CN_stroke", "zh-CN-u-co-stroke", "zh-CN_stroke"),
+        ("zh-CHS", "zh-Hans", "zh-Hans"),
+        ("x-IV", "und", "x-IV"),
+        ("", "und", "x-IV"),
+    ];
+
+    for (input, locale, windows) in tests {
+        let loc = Locale::from_windows_name(input).unwrap();
+        assert_eq!(&loc.to_string(), locale);
+        assert_eq!(&loc.to_windows_name(), windows);
+    }
+
+    assert!(Locale::from_windows_name("hu-HU_unknown").is_err());
+    assert!(Locale::from_windows_name("e1-US").is_err());
+
+    let loc: Locale = "de-DE-u-co-emoji".parse().unwrap();
+    assert_eq!(&loc.to_windows_name(), "de-DE");
+}
+
+#[test]
+fn test_clear() {
+    let mut loc: Locale = "de-AT-t-en-u-hc-h12-x-foo".parse().unwrap();
+    loc.clear();
+    assert_eq!(loc, Locale::default());
+    assert_eq!(&loc.to_string(), "und");
+}
+
+#[test]
+fn test_langid_from_locale() {
+    let langid: LanguageIdentifier = "de-AT".parse().unwrap();
+
+    let loc: Locale = "de-AT-t-en-u-hc-h12-x-foo".parse().unwrap();
+    assert_eq!(loc.as_langid(), &langid);
+    assert_eq!(loc.into_langid(), langid);
+
+    let loc: Locale = "de-AT".parse().unwrap();
+    assert_eq!(loc.into_langid(), langid);
+}
+
+#[test]
+fn test_langid_locale_conversions() {
+    let langid: LanguageIdentifier = "sr-Cyrl-RS".parse().unwrap();
+
+    let loc = Locale::from(langid.clone());
+    assert_eq!(loc.to_string(), "sr-Cyrl-RS");
+    assert!(loc.extensions.unicode.is_empty());
+
+    let loc: Locale = "sr-Cyrl-RS-u-ca-buddhist-x-foo".parse().unwrap();
+    assert_eq!(LanguageIdentifier::from(loc.clone()), langid);
+    let converted: LanguageIdentifier = loc.into();
+    assert_eq!(converted, langid);
+}
+
+#[test]
+fn test_unicode_keywords_from_iter() {
+    let mut loc: Locale = "en-US-u-foo-hc-h12".parse().unwrap();
+
+    loc.extensions
+        .unicode
+        .set_from_iter(vec![
+            ("nu", "thai"),
+            ("CA", "Islamic-Civil"),
+            ("nu", "arab"),
+        ])
+        .unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-foo-ca-islamic-civil-nu-arab");
+
+    loc.extensions
+        .unicode
+        .set_from_iter(vec![("kn", "true")])
+        .unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-foo-kn");
+
+    assert!(loc
+        .extensions
+        .unicode
+        .set_from_iter(vec![("ca", "buddhist"), ("c", "buddhist")])
+        .is_err());
+    assert!(loc
+        .extensions
+        .unicode
+        .set_from_iter(vec![("ca", "bu")])
+        .is_err());
+    assert_eq!(loc.to_string(), "en-US-u-foo-kn");
+
+    loc.extensions.unicode.set_from_iter(vec![]).unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-foo");
+}
+
+#[test]
+fn test_eq_ignores_extension_order() {
+    let tests = &[
+        (
+            "en-US-u-ca-gregory-nu-latn",
+            "en-US-u-nu-latn-ca-gregory",
+            true,
+        ),
+        ("en-u-foo-bar-ca-gregory", "en-u-bar-foo-ca-gregory", true),
+        ("en-t-m0-true-h0-hybrid", "en-t-h0-hybrid-m0-true", true),
+        (
+            "en-u-ca-gregory-t-h0-hybrid",
+            "en-t-h0-hybrid-u-ca-gregory",
+            true,
+        ),
+        (
+            "en-US-u-ca-gregory-nu-latn",
+            "en-US-u-ca-gregory-nu-arab",
+            false,
+        ),
+        ("en-US-u-ca-gregory", "en-US-u-ca-buddhist", false),
+        ("en-x-foo-bar", "en-x-bar-foo", false),
+    ];
+
+    for (input1, input2, equal) in tests {
+        let loc1: Locale = input1.parse().unwrap();
+        let loc2: Locale = input2.parse().unwrap();
+        assert_eq!(loc1 == loc2, *equal, "{} {}", input1, input2);
+    }
+}
+
+#[test]
+fn test_hash_matches_eq() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(loc: &Locale) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        loc.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut map = HashMap::new();
+    map.insert("en-US-u-ca-gregory".parse::<Locale>().unwrap(), 1);
+
+    let key: Locale = "EN-us-u-ca-gregory".parse().unwrap();
+    assert_eq!(map.get(&key), Some(&1));
+
+    let loc1: Locale = "en-US-u-ca-gregory-nu-latn-t-h0-hybrid".parse().unwrap();
+    let loc2: Locale = "en_us_t_H0_Hybrid_u_NU_latn_ca_gregory".parse().unwrap();
+    assert_eq!(loc1, loc2);
+    assert_eq!(hash(&loc1), hash(&loc2));
+}
+
+#[test]
+fn test_und_roundtrip() {
+    for input in &["und-US-u-ca-gregory", "und-Latn-t-h0-hybrid", "und-x-foo"] {
+        let loc: Locale = input.parse().unwrap();
+        assert!(loc.id.language.is_empty());
+        assert_eq!(&loc.to_string(), input);
+    }
+}
+
+#[test]
+fn test_try_from() {
+    use std::convert::TryFrom;
+
+    let loc = Locale::try_from("en_us_u_CA_buddhist").unwrap();
+    assert_eq!(&loc.to_string(), "en-US-u-ca-buddhist");
+
+    let loc = Locale::try_from(String::from("de-t-en")).unwrap();
+    assert_eq!(&loc.to_string(), "de-t-en");
+
+    assert!(Locale::try_from("en-US-ÁÁ").is_err());
+    assert!(Locale::try_from(String::from("x")).is_err());
+}
+
+#[test]
+fn test_transform_fields() {
+    let loc: Locale = "de-t-de-1996-k0-dvorak".parse().unwrap();
+    let de_1996: LanguageIdentifier = "de-1996".parse().unwrap();
+    assert_eq!(loc.extensions.transform.tlang(), Some(&de_1996));
+    assert_eq!(
+        loc.extensions
+            .transform
+            .transform_fields()
+            .collect::<Vec<_>>(),
+        &[("k0", "dvorak")]
+    );
+    assert_eq!(&loc.to_string(), "de-t-de-1996-k0-dvorak");
+
+    let loc: Locale = "und-t-M0-Ungegn-k0-true-H0-hybrid-s0-ascii-accents"
+        .parse()
+        .unwrap();
+    assert_eq!(loc.extensions.transform.tlang(), None);
+    assert_eq!(
+        loc.extensions
+            .transform
+            .transform_fields()
+            .collect::<Vec<_>>(),
+        &[
+            ("h0", "hybrid"),
+            ("k0", "true"),
+            ("m0", "ungegn"),
+            ("s0", "ascii"),
+            ("s0", "accents"),
+        ]
+    );
+    assert_eq!(
+        &loc.to_string(),
+        "und-t-h0-hybrid-k0-m0-ungegn-s0-ascii-accents"
+    );
+}
+
+#[test]
+fn test_remove_unicode_keyword() {
+    let mut loc: Locale = "de-DE-u-rg-atzzzz".parse().unwrap();
+    assert_eq!(loc.remove_unicode_keyword("RG"), Some("atzzzz".to_string()));
+    assert_eq!(&loc.to_string(), "de-DE");
+    assert!(loc.extensions.unicode.is_empty());
+
+    let mut loc: Locale = "de-DE-u-foo-kn-rg-atzzzz".parse().unwrap();
+    assert_eq!(loc.remove_unicode_keyword("kn"), Some("true".to_string()));
+    assert_eq!(loc.remove_unicode_keyword("rg"), Some("atzzzz".to_string()));
+    assert_eq!(&loc.to_string(), "de-DE-u-foo");
+
+    assert_eq!(loc.remove_unicode_keyword("ca"), None);
+    assert_eq!(loc.remove_unicode_keyword("c"), None);
+    assert_eq!(&loc.to_string(), "de-DE-u-foo");
+}
+
+#[test]
+fn test_parse_duplicate_variants() {
+    assert!("de-1996-1996-u-ca-gregory".parse::<Locale>().is_err());
+    assert!("en-t-de-1996-1996".parse::<Locale>().is_err());
+}
+
+#[test]
+fn test_grandfathered_tags() {
+    let cases = [
+        ("i-klingon", "tlh"),
+        ("zh-min-nan", "nan"),
+        ("art-lojban", "jbo"),
+        ("zh-guoyu", "cmn"),
+        ("en-GB-oed", "en-GB-oxendict"),
+    ];
+    for (input, output) in &cases {
+        let loc: Locale = input.parse().unwrap();
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(loc.to_string(), *output);
+        assert_eq!(loc.id, langid);
+    }
+
+    // Grandfathered tags only match the whole input.
+    let loc: Locale = "art-lojban-u-ca-gregory".parse().unwrap();
+    assert_eq!(loc.to_string(), "art-lojban-u-ca-gregory");
+    assert!("i-klingon-u-ca-gregory".parse::<Locale>().is_err());
+}
+
+#[test]
+fn test_unicode_keyword_bool() {
+    for input in &["en-u-kn", "en-u-kn-true", "EN-U-KN-TRUE"] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.to_string(), "en-u-kn");
+        assert!(loc.unicode_keyword_bool("kn"));
+        assert!(loc.unicode_keyword_bool("KN"));
+    }
+
+    let loc: Locale = "en-u-kn-false-ca-buddhist".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-u-ca-buddhist-kn-false");
+    assert!(!loc.unicode_keyword_bool("kn"));
+    assert!(!loc.unicode_keyword_bool("ca"));
+    assert!(!loc.unicode_keyword_bool("co"));
+    assert!(!loc.unicode_keyword_bool("k"));
+}
+
+#[test]
+fn test_set_transform_source() {
+    let mut loc: Locale = "de".parse().unwrap();
+    loc.set_transform_source("en-Latn-US".parse().unwrap());
+    assert_eq!(loc.to_string(), "de-t-en-Latn-US");
+    assert_eq!(loc, loc.to_string().parse::<Locale>().unwrap());
+
+    loc.set_transform_source("fr".parse().unwrap());
+    assert_eq!(loc.to_string(), "de-t-fr");
+
+    loc.extensions
+        .transform
+        .set_tfield("h0", &["hybrid"])
+        .unwrap();
+    assert_eq!(loc.to_string(), "de-t-fr-h0-hybrid");
+
+    loc.extensions.transform.clear_tlang();
+    assert_eq!(loc.to_string(), "de-t-h0-hybrid");
+
+    loc.set_transform_source("en".parse().unwrap());
+    loc.clear_transform();
+    assert_eq!(loc.to_string(), "de");
+    assert!(loc.extensions.transform.is_empty());
+}
+
+#[test]
+fn test_apply_extensions_str() {
+    let mut loc: Locale = "de-AT".parse().unwrap();
+    loc.apply_extensions_str("-u-ca-gregory").unwrap();
+    assert_eq!(loc.to_string(), "de-AT-u-ca-gregory");
+
+    loc.apply_extensions_str("U_FOO_NU_ARAB_CA_buddhist")
+        .unwrap();
+    assert_eq!(loc.to_string(), "de-AT-u-foo-ca-buddhist-nu-arab");
+
+    loc.apply_extensions_str("t-en-h0-hybrid-a-bar-x-priv")
+        .unwrap();
+    assert_eq!(
+        loc.to_string(),
+        "de-AT-a-bar-t-en-h0-hybrid-u-foo-ca-buddhist-nu-arab-x-priv"
+    );
+
+    loc.apply_extensions_str("t-fr-u-bar-a-baz-x-priv-other")
+        .unwrap();
+    assert_eq!(
+        loc.to_string(),
+        "de-AT-a-baz-t-fr-h0-hybrid-u-bar-foo-ca-buddhist-nu-arab-x-priv-other"
+    );
+
+    loc.apply_extensions_str("").unwrap();
+    let expected = loc.clone();
+    for input in &["ca-gregory", "u-ca-toolongvalue", "u-nu-arab-c", "a"] {
+        assert!(loc.apply_extensions_str(input).is_err(), "{}", input);
+        assert_eq!(loc, expected);
+    }
+}
+
+#[test]
+fn test_merge_extensions_from() {
+    let mut loc: Locale = "de-AT-a-aaa-t-en-k0-dvorak-u-attr-ca-buddhist-x-foo"
+        .parse()
+        .unwrap();
+    let other: Locale = "fr-a-bbb-t-k0-colemak-m0-ungegn-u-attr2-ca-gregory-nu-arab-x-bar-foo"
+        .parse()
+        .unwrap();
+
+    loc.merge_extensions_from(&other);
+    assert_eq!(
+        loc.to_string(),
+        "de-AT-a-bbb-t-en-k0-colemak-m0-ungegn-u-attr-attr2-ca-gregory-nu-arab-x-foo-bar"
+    );
+
+    let other: Locale = "fr-t-es".parse().unwrap();
+    loc.merge_extensions_from(&other);
+    assert_eq!(loc.extensions.transform.tlang().unwrap(), "es");
+
+    let before = loc.clone();
+    loc.merge_extensions_from(&Locale::default());
+    assert_eq!(loc, before);
+}
+
+#[test]
+fn test_as_ref_langid() {
+    fn language<L: AsRef<LanguageIdentifier>>(l: L) -> String {
+        l.as_ref().language.to_string()
+    }
+
+    let loc: Locale = "pl-PL-u-ca-gregory".parse().unwrap();
+    let langid: LanguageIdentifier = "pl-PL".parse().unwrap();
+    assert_eq!(language(&loc), "pl");
+    assert_eq!(language(&langid), "pl");
+    assert_eq!(language(loc), language(langid));
+}
+
+#[test]
+fn test_has_extensions() {
+    for input in &["en-US-u-kn", "en-US-t-pl", "en-US-x-foo", "en-US-u-foo"] {
+        let loc: Locale = input.parse().unwrap();
+        assert!(loc.has_extensions(), "{}", input);
+        assert!(!loc.extensions.is_empty(), "{}", input);
+    }
+
+    let mut loc: Locale = "en-US".parse().unwrap();
+    assert!(!loc.has_extensions());
+    assert_eq!(loc.extensions.unicode.keywords_len(), 0);
+
+    loc.extensions
+        .other
+        .insert('a', vec!["foo".parse().unwrap()]);
+    assert!(loc.has_extensions());
+
+    let loc: Locale = "en-US-u-ca-buddhist-nu-thai".parse().unwrap();
+    assert_eq!(loc.extensions.unicode.keywords_len(), 2);
+}
+
+#[test]
+fn test_matches_langid() {
+    let loc: Locale = "sr-Latn-RS-u-nu-latn-t-en-x-foo".parse().unwrap();
+
+    let langid: LanguageIdentifier = "sr-Latn-RS".parse().unwrap();
+    assert!(loc.matches_langid(&langid));
+
+    let other: Locale = "SR_latn_rs".parse().unwrap();
+    assert!(loc.matches_langid(&other));
+    assert!(other.matches_langid(&loc));
+
+    let langid: LanguageIdentifier = "sr-RS".parse().unwrap();
+    assert!(!loc.matches_langid(&langid));
+    let langid: LanguageIdentifier = "sr-Latn-RS-ekavsk".parse().unwrap();
+    assert!(!loc.matches_langid(&langid));
+}
+
+#[test]
+fn test_other_extensions() {
+    let tests = &[
+        ("en-a-foo", "en-a-foo"),
+        ("en-S-Bar-A-Foo-Baz", "en-a-foo-baz-s-bar"),
+        (
+            "en-z-zz-u-ca-gregory-t-pl-a-aa-x-foo",
+            "en-a-aa-t-pl-u-ca-gregory-z-zz-x-foo",
+        ),
+        ("en-1-123-x-a-b", "en-1-123-x-a-b"),
+    ];
+    for (input, output) in tests {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(&loc.to_string(), output);
+        let roundtrip: Locale = loc.to_string().parse().unwrap();
+        assert_eq!(roundtrip, loc);
+    }
+
+    let loc: Locale = "en-b-bar-a-foo-baz".parse().unwrap();
+    assert_eq!(
+        loc.extensions.other_extensions().collect::<Vec<_>>(),
+        &[('a', "foo"), ('a', "baz"), ('b', "bar")]
+    );
+    assert!(loc.has_extensions());
+
+    assert!("en-a".parse::<Locale>().is_err());
+    assert!("en-a-b-foo".parse::<Locale>().is_err());
+    assert!("en-a-foo-a-bar".parse::<Locale>().is_err());
+    assert!("en-a-toolongvalue".parse::<Locale>().is_err());
+}
+
+#[test]
+fn test_from_bytes_non_ascii() {
+    let inputs: &[&[u8]] = &[
+        b"en-\xff",
+        b"en-u-ca-\xff\xfe\xfd",
+        b"en-t-\xc3\xa9\xc3\xa9",
+        b"en-x-\x80",
+        b"en-a-\xff\xff",
+    ];
+    for input in inputs {
+        assert!(Locale::from_bytes(input).is_err(), "{:?}", input);
+    }
+}
@@ -11,7 +11,13 @@ use unic_locale_impl::Locale;
 #[proc_macro_hack]
 pub fn locale(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
-    let parsed: Locale = id.value().parse().expect("Malformed Locale Identifier");
+    let parsed: Locale = match id.value().parse::<Locale>() {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            let msg = format!("Malformed Locale Identifier \"{}\": {}", id.value(), err);
+            return TokenStream::from(syn::Error::new(id.span(), msg).to_compile_error());
+        }
+    };
 
     let (lang, script, region, variants, extensions) = parsed.into_parts();
 
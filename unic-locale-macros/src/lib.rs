@@ -3,8 +3,19 @@ pub use tinystr::{TinyStr4, TinyStr8};
 pub use unic_locale_impl::subtags;
 pub use unic_locale_impl::Locale;
 
-/// Add one to an expression.
+/// Constructs a `Locale` from a string literal, validating it,
+/// including its Unicode, transform and private use extensions,
+/// at compile time.
 ///
-/// (Documentation goes here on the re-export, not in the other crate.)
+/// A malformed literal results in a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_macros::locale;
+///
+/// let loc = locale!("de-Latn-DE-u-ca-buddhist-t-en-x-foo");
+/// assert_eq!(loc.to_string(), "de-Latn-DE-t-en-u-ca-buddhist-x-foo");
+/// ```
 #[proc_macro_hack]
 pub use unic_locale_macros_impl::locale;
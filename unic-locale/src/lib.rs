@@ -97,6 +97,11 @@
 //! The feature is optional because it increases the binary size of the library by including
 //! a data table for CLDR likelySubtags.
 //!
+//! ## `direction`
+//!
+//! The default `direction` feature provides `Locale::character_direction` and the character
+//! direction data of `unic-langid`. Disable default features for a parser-only build.
+//!
 //! [`UTS #35: Unicode LDML 3.1 Unicode Locale Identifier`]: https://unicode.org/reports/tr35/tr35.html#Unicode_locale_identifier
 //! [`Locale`]: ./struct.Locale.html
 pub use unic_locale_impl::*;
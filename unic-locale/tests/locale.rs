@@ -13,6 +13,18 @@ fn basic_test() {
 fn locale_macro_test() {
     let loc = locale!("en-US");
     assert_eq!(&loc.to_string(), "en-US");
+
+    let loc = locale!("en-US-u-ca-gregory-t-de-h0-hybrid-x-foo-bar");
+    assert_eq!(
+        loc,
+        "en-US-u-ca-gregory-t-de-h0-hybrid-x-foo-bar"
+            .parse::<Locale>()
+            .unwrap()
+    );
+    assert_eq!(
+        &loc.to_string(),
+        "en-US-t-de-h0-hybrid-u-ca-gregory-x-foo-bar"
+    );
 }
 
 #[test]